@@ -0,0 +1,73 @@
+use crate::{
+    checked_get_year_start_with, get_day_count, get_day_count_with, get_year_start,
+    YearStartProvider, REPUBLICAN_SECONDS_PER_DAY,
+};
+use std::ops::RangeInclusive;
+
+/// A purely arithmetic calendar, in which every 4th year (3, 7, 11...) is sextile.
+///
+/// This is the rule used by many other converters, such as PHP's `jdtofrench`.
+/// It matches the equinoxes in the first years of the Republic, but drifts afterwards.
+/// Use it as a [`YearStartProvider`] to exchange dates with such systems.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Arithmetic;
+
+impl YearStartProvider for Arithmetic {
+    fn year_start0(&self, republican_year0: i64) -> i128 {
+        let days = (republican_year0 as i128 + 1) * 1461;
+        (days.div_euclid(4) - 365) * REPUBLICAN_SECONDS_PER_DAY as i128
+    }
+}
+
+/// Returns the years of the range in which the [`Arithmetic`] calendar disagrees with the equinoxes.
+///
+/// A year is listed if it starts on another day, or if it doesn't have the same number of days.
+/// Dates of these years are converted differently by the two calendars.
+pub fn arithmetic_divergences(republican_years: RangeInclusive<i64>) -> Vec<i64> {
+    republican_years
+        .filter(|year| *year != 0)
+        .filter(|year| {
+            checked_get_year_start_with(*year, &Arithmetic) != Some(get_year_start(*year))
+                || get_day_count_with(*year, &Arithmetic) != get_day_count(*year)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Arithmetic.year_start0(0), 0);
+        assert_eq!(get_day_count_with(1, &Arithmetic), 365);
+        assert_eq!(get_day_count_with(3, &Arithmetic), 366);
+        assert_eq!(get_day_count_with(7, &Arithmetic), 366);
+        assert_eq!(get_day_count_with(-2, &Arithmetic), 366);
+        assert_eq!(get_day_count_with(-1, &Arithmetic), 365);
+
+        // jdtofrench(2375840) is 1/1/1, jdtofrench(2380953) is 1/1/15
+        let ts = Timestamp::from_seconds((2380953 - 2375840) * REPUBLICAN_SECONDS_PER_DAY);
+        let date = Date::from_timestamp_with(ts, &Arithmetic);
+        assert_eq!((date.year(), date.num_month(), date.day()), (15, 1, 1));
+    }
+
+    #[test]
+    fn test_divergences() {
+        let divergences = arithmetic_divergences(1..=30);
+        assert!(!divergences.contains(&1));
+        assert!(!divergences.contains(&3));
+        assert!(!divergences.contains(&15));
+        // Year 19 is sextile in the arithmetic calendar, year 20 is sextile according to the equinoxes
+        assert_eq!(divergences[..2], [19, 20]);
+        assert!(divergences.iter().all(|year| *year != 0));
+        for year in divergences {
+            assert!(
+                Date::from_ymd(year, 1, 1).timestamp()
+                    != Date::from_ymd(year, 1, 1).timestamp_with(&Arithmetic)
+                    || get_day_count(year) != get_day_count_with(year, &Arithmetic)
+            );
+        }
+    }
+}
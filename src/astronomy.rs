@@ -0,0 +1,174 @@
+//! Computation of autumn equinoxes, for years outside of the IMCCE table.
+//!
+//! Sources:
+//! Jean Meeus, Astronomical Algorithms, 2nd edition, chapter 27 (equinoxes and solstices).
+//! Fred Espenak and Jean Meeus, Five Millennium Canon of Solar Eclipses, polynomial expressions for ΔT.
+//!
+//! The periodic terms of chapter 27 are a truncation of the VSOP87 theory, accurate to about a minute for the
+//! years -1000 to 3000. Outside of these years the series diverge, so the equinox is extrapolated with the
+//! mean tropical year from the nearest bound, and ΔT is kept at its value on that bound.
+
+use crate::REPUBLICAN_SECONDS_PER_DAY;
+use crate::years::{gregorian_year_of_year0, republican_year0};
+
+/// Julian day (UT) of the republican epoch, 1792-09-22 00:00 at the Paris observatory.
+const REPUBLICAN_EPOCH_JULIAN_DAY: f64 = 2375839.4875;
+
+/// Range of gregorian years in which Meeus' series are valid.
+const MIN_GREGORIAN_YEAR: i64 = -1000;
+const MAX_GREGORIAN_YEAR: i64 = 3000;
+
+/// Mean tropical year, in tenths of republican seconds (365.242189 days).
+const TROPICAL_YEAR_DECISECONDS: i128 = 365242189;
+
+/// Periodic terms (A, B, C) of table 27.C.
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// Julian ephemeris day of the september equinox (tables 27.A and 27.B, then periodic terms).
+fn equinox_julian_ephemeris_day(gregorian_year: i64) -> f64 {
+    let mean = if gregorian_year < 1000 {
+        let y = gregorian_year as f64 / 1000.0;
+        polynomial(y, &[1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074])
+    } else {
+        let y = (gregorian_year - 2000) as f64 / 1000.0;
+        polynomial(y, &[2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078])
+    };
+
+    let t = (mean - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+
+    mean + 0.00001 * s / delta_lambda
+}
+
+/// Difference between Terrestrial Time and Universal Time, in seconds.
+fn delta_t(gregorian_year: f64) -> f64 {
+    let y = gregorian_year;
+    let long_term = |y: f64| -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2);
+    match y {
+        y if y < -500.0 => long_term(y),
+        y if y < 500.0 => polynomial(
+            y / 100.0,
+            &[10583.6, -1014.41, 33.78311, -5.952053, -0.1798452, 0.022174192, 0.0090316521],
+        ),
+        y if y < 1600.0 => polynomial(
+            (y - 1000.0) / 100.0,
+            &[1574.2, -556.01, 71.23472, 0.319781, -0.8503463, -0.005050998, 0.0083572073],
+        ),
+        y if y < 1700.0 => polynomial(y - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0]),
+        y if y < 1800.0 => polynomial(
+            y - 1700.0,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0],
+        ),
+        y if y < 1860.0 => polynomial(
+            y - 1800.0,
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+        ),
+        y if y < 1900.0 => polynomial(
+            y - 1860.0,
+            &[7.62, 0.5737, -0.251754, 0.01680668, -0.0004473624, 1.0 / 233174.0],
+        ),
+        y if y < 1920.0 => polynomial(
+            y - 1900.0,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        ),
+        y if y < 1941.0 => polynomial(y - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936]),
+        y if y < 1961.0 => polynomial(y - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0]),
+        y if y < 1986.0 => polynomial(y - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0]),
+        y if y < 2005.0 => polynomial(
+            y - 2000.0,
+            &[63.86, 0.3345, -0.060374, 0.0017275, 0.000651814, 0.00002373599],
+        ),
+        y if y < 2050.0 => polynomial(y - 2000.0, &[62.92, 0.32217, 0.005589]),
+        y if y < 2150.0 => long_term(y) - 0.5628 * (2150.0 - y),
+        y => long_term(y),
+    }
+}
+
+/// Computes the equinox of a gregorian year inside the range of validity of the series, as a republican timestamp.
+fn compute_valid_equinox(gregorian_year: i64) -> i128 {
+    let julian_day = equinox_julian_ephemeris_day(gregorian_year)
+        - delta_t(gregorian_year as f64 + 0.72) / 86400.0;
+    ((julian_day - REPUBLICAN_EPOCH_JULIAN_DAY) * REPUBLICAN_SECONDS_PER_DAY as f64).round() as i128
+}
+
+/// Computes the autumn equinox of any year without overflowing.
+pub(crate) fn compute_equinox0(republican_year0: i64) -> i128 {
+    let gregorian_year = gregorian_year_of_year0(republican_year0);
+    let bound = gregorian_year.clamp(MIN_GREGORIAN_YEAR as i128, MAX_GREGORIAN_YEAR as i128);
+    let equinox = compute_valid_equinox(bound as i64);
+    equinox + ((gregorian_year - bound) * TROPICAL_YEAR_DECISECONDS).div_euclid(10)
+}
+
+/// Computes the autumn equinox of a republican year with Meeus' algorithm.
+///
+/// The result is a republican timestamp, like [`get_equinox`](crate::get_equinox).
+/// Unlike the IMCCE table, it can be computed for any year, but is only accurate to about a minute from gregorian year -1000 to 3000
+/// (republican years -2792 to 1209).
+/// Further away, the equinox is extrapolated with a mean tropical year and can be off by days.
+/// Returns `None` if the result cannot be represented by an `i64`.
+pub fn compute_equinox(republican_year: i64) -> Option<i64> {
+    i64::try_from(compute_equinox0(republican_year0(republican_year))).ok()
+}
+
+#[test]
+fn test_compute_equinox() {
+    use crate::get_equinox;
+
+    // Compare with the IMCCE table
+    for year in (-209..=1208).filter(|year| *year != 0) {
+        let computed = compute_equinox(year).unwrap();
+        let expected = get_equinox(year);
+        assert!((computed - expected).abs() < 200, "year {year}: {computed} != {expected}");
+    }
+
+    // The equinox keeps moving by a tropical year far from the table
+    for year in [-100_000_000, -3000, 3000, 100_000_000] {
+        let length = compute_equinox(year + 1).unwrap() - compute_equinox(year).unwrap();
+        assert!((36524000..=36525000).contains(&length), "year {year}: {length}");
+    }
+    assert!(compute_equinox(i64::MAX).is_none());
+    assert!(compute_equinox(i64::MIN).is_none());
+}
@@ -1,33 +1,46 @@
 use crate::*;
 use std::ops::{Add, AddAssign};
 
+fn timestamp_from_chrono<Tz: chrono::TimeZone>(
+    value: &chrono::DateTime<Tz>,
+    offset: Offset,
+) -> Result<Timestamp, Error> {
+    let unix_nanos = value.timestamp() as i128 * NANOS_PER_SECOND
+        + value.timestamp_subsec_nanos() as i128;
+    Timestamp::checked_from_unix_nanos_with(unix_nanos, offset).ok_or(Error::TimestampOutOfRange)
+}
+
+fn timestamp_to_chrono(
+    ts: Timestamp,
+    offset: Offset,
+) -> Result<chrono::DateTime<chrono::Utc>, Error> {
+    let unix_nanos = ts.to_unix_nanos_with(offset);
+    let seconds = i64::try_from(unix_nanos.div_euclid(NANOS_PER_SECOND))
+        .map_err(|_| Error::ChronoOutOfRange)?;
+    let nanos = unix_nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+    chrono::DateTime::from_timestamp(seconds, nanos).ok_or(Error::ChronoOutOfRange)
+}
+
 // DateTime to chrono stuff
 
 impl TryFrom<DateTime> for chrono::DateTime<chrono::Utc> {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: DateTime) -> Result<Self, Self::Error> {
-        match Self::from_timestamp(value.timestamp().to_unix(), 0) {
-            Some(dt) => Ok(dt),
-            None => Err(()),
-        }
+        timestamp_to_chrono(value.timestamp(), Offset::DEFAULT)
     }
 }
 
 impl TryFrom<DateTime> for chrono::NaiveDateTime {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: DateTime) -> Result<Self, Self::Error> {
-        let ts = value.timestamp().to_unix();
-        match chrono::DateTime::from_timestamp(ts, 0) {
-            Some(dt) => Ok(dt.naive_utc()),
-            None => Err(()),
-        }
+        timestamp_to_chrono(value.timestamp(), Offset::DEFAULT).map(|dt| dt.naive_utc())
     }
 }
 
 impl TryFrom<DateTime> for chrono::NaiveDate {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: DateTime) -> Result<Self, Self::Error> {
         let naive_dt: chrono::NaiveDateTime = value.try_into()?;
@@ -39,78 +52,93 @@ impl TryFrom<DateTime> for chrono::NaiveDate {
 
 impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for DateTime {
     fn from(value: chrono::DateTime<Tz>) -> Self {
-        let ts = value.timestamp();
-        let ts = Timestamp::from_unix(ts);
+        let ts = timestamp_from_chrono(&value, Offset::DEFAULT).expect("timestamp out of range");
         Self::from_timestamp(ts)
     }
 }
 
 impl TryFrom<chrono::NaiveDateTime> for DateTime {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: chrono::NaiveDateTime) -> Result<Self, Self::Error> {
-        let ts = value.and_utc().timestamp();
-        let ts = Timestamp::from_unix(ts);
-        Ok(Self::from_timestamp(ts))
+        Ok(Self::from_timestamp(timestamp_from_chrono(&value.and_utc(), Offset::DEFAULT)?))
     }
 }
 
 impl TryFrom<chrono::NaiveDate> for DateTime {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: chrono::NaiveDate) -> Result<Self, Self::Error> {
-        let naive_dt = value.and_hms_opt(0, 0, 0).ok_or(())?;
+        let naive_dt = value.and_hms_opt(0, 0, 0).ok_or(Error::ChronoOutOfRange)?;
         let ts = naive_dt.and_utc().timestamp();
-        let ts = Timestamp::from_unix(ts);
+        let ts = Timestamp::from_unix_with(ts, Offset::DEFAULT);
         Ok(Self::from_timestamp(ts))
     }
 }
 
+impl DateTime {
+    /// Same as `DateTime::from(value)` with an explicit [`Offset`].
+    ///
+    /// Returns [`Error::TimestampOutOfRange`] if an [`Offset::Custom`] moves the instant out of the range of timestamps.
+    pub fn from_chrono_with<Tz: chrono::TimeZone>(
+        value: chrono::DateTime<Tz>,
+        offset: Offset,
+    ) -> Result<Self, Error> {
+        Ok(Self::from_timestamp(timestamp_from_chrono(&value, offset)?))
+    }
+
+    /// Same as `chrono::DateTime::try_from(self)` with an explicit [`Offset`].
+    pub fn to_chrono_with(&self, offset: Offset) -> Result<chrono::DateTime<chrono::Utc>, Error> {
+        timestamp_to_chrono(self.timestamp(), offset)
+    }
+}
+
+/// Converts gregorian seconds to decimal seconds, rounding down to the nanosecond.
+impl From<chrono::Duration> for Duration {
+    fn from(value: chrono::Duration) -> Self {
+        let gregorian_nanos =
+            value.num_seconds() as i128 * NANOS_PER_SECOND + value.subsec_nanos() as i128;
+        Duration::from_nanos(
+            (gregorian_nanos * REPUBLICAN_SECONDS_PER_DAY as i128)
+                .div_euclid(GREGORIAN_SECONDS_PER_DAY as i128),
+        )
+    }
+}
+
 impl Add<chrono::Duration> for DateTime {
     type Output = Self;
 
     fn add(self, rhs: chrono::Duration) -> Self::Output {
-        let seconds = self.timestamp().seconds
-            + rhs.num_seconds() * REPUBLICAN_SECONDS_PER_DAY / GREGORIAN_SECONDS_PER_DAY;
-        Self::from_timestamp(Timestamp { seconds })
+        self + Duration::from(rhs)
     }
 }
 
 impl AddAssign<chrono::Duration> for DateTime {
     fn add_assign(&mut self, rhs: chrono::Duration) {
-        let ts = self.timestamp().seconds
-            + rhs.num_seconds() * REPUBLICAN_SECONDS_PER_DAY / GREGORIAN_SECONDS_PER_DAY;
-        *self = Self::from_timestamp(Timestamp { seconds: ts });
+        *self += Duration::from(rhs);
     }
 }
 
 // Date to chrono stuff
 
 impl TryFrom<Date> for chrono::DateTime<chrono::Utc> {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: Date) -> Result<Self, Self::Error> {
-        match Self::from_timestamp(value.timestamp().to_unix(), 0) {
-            Some(dt) => Ok(dt),
-            None => Err(()),
-        }
+        timestamp_to_chrono(value.timestamp(), Offset::DEFAULT)
     }
 }
 
 impl TryFrom<Date> for chrono::NaiveDateTime {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: Date) -> Result<Self, Self::Error> {
-        let ts = value.timestamp().to_unix();
-        match chrono::DateTime::from_timestamp(ts, 0) {
-            Some(dt) => Ok(dt.naive_utc()),
-            None => Err(()),
-        }
+        timestamp_to_chrono(value.timestamp(), Offset::DEFAULT).map(|dt| dt.naive_utc())
     }
 }
 
 impl TryFrom<Date> for chrono::NaiveDate {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: Date) -> Result<Self, Self::Error> {
         let naive_dt: chrono::NaiveDateTime = value.try_into()?;
@@ -122,29 +150,128 @@ impl TryFrom<Date> for chrono::NaiveDate {
 
 impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Date {
     fn from(value: chrono::DateTime<Tz>) -> Self {
-        let ts = value.timestamp();
-        let ts = Timestamp::from_unix(ts);
+        let ts = timestamp_from_chrono(&value, Offset::DEFAULT).expect("timestamp out of range");
         Self::from_timestamp(ts)
     }
 }
 
 impl TryFrom<chrono::NaiveDateTime> for Date {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: chrono::NaiveDateTime) -> Result<Self, Self::Error> {
-        let ts = value.and_utc().timestamp();
-        let ts = Timestamp::from_unix(ts);
-        Ok(Self::from_timestamp(ts))
+        Ok(Self::from_timestamp(timestamp_from_chrono(&value.and_utc(), Offset::DEFAULT)?))
     }
 }
 
 impl TryFrom<chrono::NaiveDate> for Date {
-    type Error = ();
+    type Error = Error;
 
     fn try_from(value: chrono::NaiveDate) -> Result<Self, Self::Error> {
-        let naive_dt = value.and_hms_opt(0, 0, 0).ok_or(())?;
+        let naive_dt = value.and_hms_opt(0, 0, 0).ok_or(Error::ChronoOutOfRange)?;
         let ts = naive_dt.and_utc().timestamp();
-        let ts = Timestamp::from_unix(ts);
+        let ts = Timestamp::from_unix_with(ts, Offset::DEFAULT);
         Ok(Self::from_timestamp(ts))
     }
 }
+
+impl Date {
+    /// Same as `Date::from(value)` with an explicit [`Offset`].
+    ///
+    /// Returns [`Error::TimestampOutOfRange`] if an [`Offset::Custom`] moves the instant out of the range of timestamps.
+    pub fn from_chrono_with<Tz: chrono::TimeZone>(
+        value: chrono::DateTime<Tz>,
+        offset: Offset,
+    ) -> Result<Self, Error> {
+        Ok(Self::from_timestamp(timestamp_from_chrono(&value, offset)?))
+    }
+
+    /// Same as `chrono::DateTime::try_from(self)` with an explicit [`Offset`].
+    pub fn to_chrono_with(&self, offset: Offset) -> Result<chrono::DateTime<chrono::Utc>, Error> {
+        timestamp_to_chrono(self.timestamp(), offset)
+    }
+}
+
+// GregorianDate to chrono stuff
+
+impl TryFrom<GregorianDate> for chrono::NaiveDate {
+    type Error = Error;
+
+    fn try_from(value: GregorianDate) -> Result<Self, Self::Error> {
+        let year = i32::try_from(value.year).map_err(|_| Error::ChronoOutOfRange)?;
+        Self::from_ymd_opt(year, value.month as u32, value.day as u32)
+            .ok_or(Error::ChronoOutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_subsecond_round_trip() {
+        let instant = chrono::Utc
+            .with_ymd_and_hms(2024, 3, 14, 15, 9, 26)
+            .single()
+            .unwrap()
+            + chrono::Duration::milliseconds(535);
+        let datetime = DateTime::from(instant);
+        let back: chrono::DateTime<chrono::Utc> = datetime.try_into().unwrap();
+        assert_eq!(back, instant);
+
+        let naive = instant.naive_utc() + chrono::Duration::nanoseconds(897);
+        let datetime: DateTime = naive.try_into().unwrap();
+        let back: chrono::NaiveDateTime = datetime.try_into().unwrap();
+        assert_eq!(back, naive);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let far = DateTime::from_ymd(1_000_000, 1, 1);
+        let result: Result<chrono::DateTime<chrono::Utc>, _> = far.try_into();
+        assert_eq!(result, Err(Error::ChronoOutOfRange));
+        let result: Result<chrono::NaiveDate, _> = Date::from_ymd(-1_000_000, 1, 1).try_into();
+        assert_eq!(result, Err(Error::ChronoOutOfRange));
+    }
+
+    #[test]
+    fn test_gregorian_date() {
+        let start = YearInfo::new(233).unwrap().gregorian_start;
+        let naive: chrono::NaiveDate = start.try_into().unwrap();
+        assert_eq!(naive, chrono::NaiveDate::from_ymd_opt(2024, 9, 22).unwrap());
+    }
+
+    #[test]
+    fn test_offset() {
+        let midnight = chrono::Utc
+            .with_ymd_and_hms(1792, 9, 22, 0, 0, 0)
+            .single()
+            .unwrap();
+        let datetime = DateTime::from_chrono_with(midnight, Offset::Zero).unwrap();
+        assert_eq!(datetime, DateTime::from_ymd(1, 1, 1));
+        assert_eq!(datetime.to_chrono_with(Offset::Zero), Ok(midnight));
+
+        let datetime = DateTime::from_chrono_with(midnight, Offset::ArticleOne).unwrap();
+        assert_eq!(datetime.hms(), (0, 12, 50));
+        assert_eq!(
+            DateTime::from_chrono_with(midnight, Offset::DEFAULT),
+            Ok(DateTime::from(midnight))
+        );
+
+        let date = Date::from_chrono_with(midnight, Offset::Custom(-1)).unwrap();
+        assert_eq!(date.year(), -1);
+        assert_eq!(
+            date.to_chrono_with(Offset::Custom(-1)),
+            Ok(midnight - chrono::Duration::days(1) + chrono::Duration::seconds(1))
+        );
+
+        assert_eq!(
+            Date::from_chrono_with(midnight, Offset::Custom(i64::MIN)),
+            Err(Error::TimestampOutOfRange)
+        );
+        assert_eq!(
+            date.to_chrono_with(Offset::Custom(i64::MAX)),
+            Err(Error::ChronoOutOfRange)
+        );
+    }
+}
@@ -9,8 +9,16 @@ pub struct Date {
 
 impl Date {
     pub fn from_timestamp(timestamp: Timestamp) -> Self {
-        let year0 = ts_to_year0(timestamp.seconds);
-        let seconds_in_year = timestamp.seconds - get_year_start0(year0);
+        Self::from_timestamp_with(timestamp, &Imcce)
+    }
+
+    /// Same as [`Date::from_timestamp`] with an explicit [`YearStartProvider`].
+    pub fn from_timestamp_with<P: YearStartProvider + ?Sized>(
+        timestamp: Timestamp,
+        provider: &P,
+    ) -> Self {
+        let year0 = ts_to_year0_with(timestamp.seconds, provider);
+        let seconds_in_year = (timestamp.seconds as i128 - provider.year_start0(year0)) as i64;
 
         let month0 = seconds_in_year.div_euclid(SECONDS_PER_MONTH);
         let seconds_in_month = seconds_in_year.rem_euclid(SECONDS_PER_MONTH);
@@ -29,46 +37,157 @@ impl Date {
     /// Panics if:
     /// - year is 0,
     /// - month is not in [1, 13],
-    /// - day is not in [1, 30].
+    /// - day is not in [1, 30],
+    /// - the Sansculottide day is beyond the end of the year (day 6 only exists in sextile years),
+    /// - the date cannot be represented by a [`Timestamp`].
+    ///
+    /// See [`Date::try_from_ymd`] for a non-panicking version,
+    /// and [`Date::from_ymd_normalized`] to roll over to the next year.
     pub fn from_ymd(year: i64, month: i64, day: i64) -> Self {
+        Self::try_from_ymd(year, month, day).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - month is not in [0, 12],
+    /// - day is not in [0, 29],
+    /// - the Sansculottide day is beyond the end of the year (day 5 only exists in sextile years),
+    /// - the date cannot be represented by a [`Timestamp`].
+    ///
+    /// See [`Date::try_from_ymd0`] for a non-panicking version.
+    pub fn from_ymd0(year0: i64, month0: i64, day0: i64) -> Self {
+        Self::try_from_ymd0(year0, month0, day0).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Builds a date from fields that are already valid, such as those of a [`DateTime`], without checking them again.
+    pub(crate) const fn from_ymd0_unchecked(year0: i64, month0: i64, day0: i64) -> Self {
+        Self {
+            year0,
+            month0,
+            day0,
+        }
+    }
+
+    /// Same as [`Date::from_ymd`] but returns an error instead of panicking.
+    pub fn try_from_ymd(year: i64, month: i64, day: i64) -> Result<Self, Error> {
+        Self::try_from_ymd_with(year, month, day, &Imcce)
+    }
+
+    /// Same as [`Date::try_from_ymd`] with an explicit [`YearStartProvider`], which decides whether the year is sextile.
+    pub fn try_from_ymd_with<P: YearStartProvider + ?Sized>(
+        year: i64,
+        month: i64,
+        day: i64,
+        provider: &P,
+    ) -> Result<Self, Error> {
         let year0 = match year.cmp(&0) {
             std::cmp::Ordering::Greater => year - 1,
             std::cmp::Ordering::Less => year,
-            std::cmp::Ordering::Equal => panic!("year cannot be 0"),
+            std::cmp::Ordering::Equal => return Err(Error::YearZero),
         };
-        assert!((1..=13).contains(&month), "month must be in [1, 13]");
-        assert!((1..=30).contains(&day), "day must be in [1, 30]");
-        let month0 = month - 1;
-        let day0 = day - 1;
-        Self {
+        if !(1..=13).contains(&month) {
+            return Err(Error::MonthOutOfRange);
+        }
+        if !(1..=30).contains(&day) {
+            return Err(Error::DayOutOfRange);
+        }
+        Self::try_from_ymd0_with(year0, month - 1, day - 1, provider)
+    }
+
+    /// Same as [`Date::from_ymd0`] but returns an error instead of panicking.
+    pub fn try_from_ymd0(year0: i64, month0: i64, day0: i64) -> Result<Self, Error> {
+        Self::try_from_ymd0_with(year0, month0, day0, &Imcce)
+    }
+
+    /// Same as [`Date::try_from_ymd0`] with an explicit [`YearStartProvider`], which decides whether the year is sextile.
+    pub fn try_from_ymd0_with<P: YearStartProvider + ?Sized>(
+        year0: i64,
+        month0: i64,
+        day0: i64,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        if !(0..=12).contains(&month0) {
+            return Err(Error::Month0OutOfRange);
+        }
+        if !(0..=29).contains(&day0) {
+            return Err(Error::Day0OutOfRange);
+        }
+        let date = Self {
             year0,
             month0,
             day0,
+        };
+        date.checked_timestamp_with(provider)
+            .ok_or(Error::TimestampOutOfRange)?;
+        if month0 == 12 && day0 >= get_day_count0(year0, provider) - 12 * DAYS_PER_MONTH {
+            return Err(Error::SansculottideDayOutOfRange);
         }
+        Ok(date)
     }
 
+    /// Same as [`Date::from_ymd`], but Sansculottide days beyond the end of the year roll over to the next year.
+    /// For instance, the 6th Sansculottide day of a non-sextile year is the 1st Vendémiaire of the next year.
+    ///
     /// # Panics
     ///
     /// Panics if:
-    /// - month is not in [0, 12],
-    /// - day is not in [0, 29].
-    pub fn from_ymd0(year0: i64, month0: i64, day0: i64) -> Self {
-        assert!((0..=12).contains(&month0), "month0 must be in [0, 12]");
-        assert!((0..=29).contains(&day0), "day0 must be in [0, 29]");
-        Self {
-            year0,
-            month0,
-            day0,
+    /// - year is 0,
+    /// - month is not in [1, 13],
+    /// - day is not in [1, 30],
+    /// - the date cannot be represented by a [`Timestamp`].
+    pub fn from_ymd_normalized(year: i64, month: i64, day: i64) -> Self {
+        Self::try_from_ymd_normalized(year, month, day).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Same as [`Date::from_ymd_normalized`] but returns an error instead of panicking.
+    pub fn try_from_ymd_normalized(year: i64, month: i64, day: i64) -> Result<Self, Error> {
+        Self::try_from_ymd_normalized_with(year, month, day, &Imcce)
+    }
+
+    /// Same as [`Date::try_from_ymd_normalized`] with an explicit [`YearStartProvider`].
+    pub fn try_from_ymd_normalized_with<P: YearStartProvider + ?Sized>(
+        year: i64,
+        month: i64,
+        day: i64,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        match Self::try_from_ymd_with(year, month, day, provider) {
+            Err(Error::SansculottideDayOutOfRange) => {
+                let overflowing = Self {
+                    year0: if year > 0 { year - 1 } else { year },
+                    month0: month - 1,
+                    day0: day - 1,
+                };
+                let timestamp = overflowing
+                    .checked_timestamp_with(provider)
+                    .ok_or(Error::TimestampOutOfRange)?;
+                Ok(Self::from_timestamp_with(timestamp, provider))
+            }
+            result => result,
         }
     }
 
+    /// Same as [`Date::from_ymd`] but returns `None` instead of panicking.
+    pub fn checked_from_ymd(year: i64, month: i64, day: i64) -> Option<Self> {
+        Self::try_from_ymd(year, month, day).ok()
+    }
+
+    /// Same as [`Date::from_ymd0`] but returns `None` instead of panicking.
+    pub fn checked_from_ymd0(year0: i64, month0: i64, day0: i64) -> Option<Self> {
+        Self::try_from_ymd0(year0, month0, day0).ok()
+    }
+
     /// Returns the franciade number starting from 0.
     ///
     /// A franciade is defined as 4 years, the first franciade ending in year 3.
     /// It is *not* defined as a period of years ending with a sextile year.
     /// Not all franciades are `365*4+1` days long.
+    ///
+    /// The first franciade contains the years -1, 1, 2 and 3, as for [`DateTime::franciade0`].
+    /// This method used to disagree with [`DateTime`] and to return -1 for the years 1 and 2.
     pub fn franciade0(&self) -> i64 {
-        ((self.year0 + 2) / 4) - 1
+        franciade0_of_year0(self.year0)
     }
 
     /// Returns the franciade number starting from 1.
@@ -77,12 +196,7 @@ impl Date {
     /// It is *not* defined as a period of years ending with a sextile year.
     /// Not all franciades are `365*4+1` days long.
     pub fn franciade(&self) -> i64 {
-        let franciade0 = self.franciade0();
-        if franciade0 >= 0 {
-            franciade0 + 1
-        } else {
-            franciade0
-        }
+        republican_year(self.franciade0())
     }
 
     /// Returns the year but starting from 0.
@@ -131,6 +245,21 @@ impl Date {
         day_name(self.month(), self.day())
     }
 
+    /// Returns the season of the date, or `None` during the Sansculotides.
+    pub fn season(&self) -> Option<Season> {
+        self.month().season()
+    }
+
+    /// Returns the feast of the day, with its article, gender, number and kind.
+    pub fn feast(&self) -> Feast {
+        Feast::new(self.month(), self.day())
+    }
+
+    /// Returns the name of the day in a [`Locale`], or in French if the locale has no glossary of the feasts.
+    pub fn day_name_in<'a>(&self, locale: &'a dyn Locale) -> &'a str {
+        day_name_in(locale, self.month(), self.day())
+    }
+
     /// Returns the name of the day preceded by the appropriate article
     pub fn day_name_with_article(&self) -> &'static str {
         day_name_with_article(self.month(), self.day())
@@ -166,105 +295,241 @@ impl Date {
 
     /// Returns the timestamp
     pub fn timestamp(&self) -> Timestamp {
-        Timestamp {
-            seconds: get_year_start0(self.year0)
-                + self.month0 * SECONDS_PER_MONTH
-                + self.day0 * SECONDS_PER_DAY,
-        }
+        self.timestamp_with(&Imcce)
     }
 
-    fn fmt_default(&self, f: &mut impl std::io::Write) -> std::io::Result<()> {
-        write!(
-            f,
-            "{} {} {} {}",
-            self.decade_day(),
-            self.day(),
-            self.month(),
-            self.year(),
-        )
+    /// Same as [`Date::timestamp`] with an explicit [`YearStartProvider`].
+    pub fn timestamp_with<P: YearStartProvider + ?Sized>(&self, provider: &P) -> Timestamp {
+        self.checked_timestamp_with(provider)
+            .expect("date cannot be represented by a timestamp")
     }
 
-    fn fmt_traditional(&self, f: &mut impl std::io::Write) -> std::io::Result<()> {
-        let mut remaining_years = self.year();
-        let thousand_years = remaining_years.div_euclid(1000);
-        remaining_years -= thousand_years * 1000;
-        let thousand_years = "M".repeat(thousand_years as usize);
-        let five_hundred_years = remaining_years.div_euclid(500);
-        remaining_years -= five_hundred_years * 500;
-        let five_hundred_years = match five_hundred_years {
-            0 => "",
-            1 => "D",
-            _ => unreachable!(),
-        };
-        let hundred_years = remaining_years.div_euclid(100);
-        remaining_years -= hundred_years * 100;
-        let hundred_years = match hundred_years {
-            0 => "",
-            1 => "C",
-            2 => "CC",
-            3 => "CCC",
-            4 => "CD",
-            _ => unreachable!(),
-        };
-        let fifty_years = remaining_years.div_euclid(50);
-        remaining_years -= fifty_years * 50;
-        let fifty_years = match fifty_years {
-            0 => "",
-            1 => "L",
-            _ => unreachable!(),
-        };
-        let ten_years = remaining_years.div_euclid(10);
-        remaining_years -= ten_years * 10;
-        let ten_years = match ten_years {
-            0 => "",
-            1 => "X",
-            2 => "XX",
-            3 => "XXX",
-            4 => "XL",
-            _ => unreachable!(),
-        };
-        let five_years = remaining_years.div_euclid(5);
-        remaining_years -= five_years * 5;
-        let five_years = match five_years {
-            0 => "",
-            1 => "V",
-            _ => unreachable!(),
-        };
-        let one_year = remaining_years;
-        let one_year = match one_year {
-            0 => "",
-            1 => "I",
-            2 => "II",
-            3 => "III",
-            4 => "IV",
-            _ => unreachable!(),
-        };
+    /// Returns the timestamp, or `None` if it would overflow.
+    pub fn checked_timestamp(&self) -> Option<Timestamp> {
+        self.checked_timestamp_with(&Imcce)
+    }
+
+    /// Same as [`Date::checked_timestamp`] with an explicit [`YearStartProvider`].
+    pub fn checked_timestamp_with<P: YearStartProvider + ?Sized>(
+        &self,
+        provider: &P,
+    ) -> Option<Timestamp> {
+        let year_start = i64::try_from(provider.year_start0(self.year0)).ok()?;
+        let seconds = year_start
+            .checked_add(self.month0 * SECONDS_PER_MONTH + self.day0 * SECONDS_PER_DAY)?;
+        Some(Timestamp::from_seconds(seconds))
+    }
+
+    /// Formats the date with a `strftime`-like pattern, see the [`format` module](crate::format) for the specifiers.
+    /// Time specifiers are not supported.
+    pub fn format<'a>(&self, pattern: &'a str) -> DelayedFormat<'a> {
+        self.format_with(pattern, &French)
+    }
+
+    /// Same as [`Date::format`] with the names of a [`Locale`].
+    pub fn format_with<'a>(&self, pattern: &'a str, locale: &'a dyn Locale) -> DelayedFormat<'a> {
+        DelayedFormat::new(self.clone(), None, pattern, locale)
+    }
 
+    /// Returns a [`Display`](std::fmt::Display) implementation with the names of a [`Locale`].
+    pub fn localized<'a>(&'a self, locale: &'a dyn Locale) -> Localized<'a, Self> {
+        Localized::new(self, locale)
+    }
+
+    /// Parses a date with a pattern of the [`format` module](crate::format), such as `%A %d %B an %R`.
+    ///
+    /// Names are matched regardless of case and accents, years can be written in arabic or roman numerals.
+    /// Time specifiers are read but ignored.
+    /// Fails if some fields don't agree with each other, such as a day of the decade that doesn't match the date.
+    pub fn parse_from_str(s: &str, pattern: &str) -> Result<Self, Error> {
+        format::parse(s, pattern)?.to_date()
+    }
+
+    fn fmt_default(&self, f: &mut impl std::fmt::Write, locale: &dyn Locale) -> std::fmt::Result {
+        write!(f, "{}", self.format_with("%A %-d %B %Y", locale))
+    }
+
+    fn fmt_traditional(
+        &self,
+        f: &mut impl std::fmt::Write,
+        locale: &dyn Locale,
+    ) -> std::fmt::Result {
         write!(
             f,
-            "{} {} {} an {}{}{}{}{}{}{}",
-            self.decade_day(),
-            self.day(),
-            self.month(),
-            thousand_years,
-            five_hundred_years,
-            hundred_years,
-            fifty_years,
-            ten_years,
-            five_years,
-            one_year
+            "{} {} {}",
+            self.format_with("%A %-d %B", locale),
+            locale.year_word(),
+            self.format_with("%R", locale)
         )
     }
 
     pub fn to_string_default(&self) -> String {
-        let mut s = Vec::new();
-        self.fmt_default(&mut s).unwrap();
-        String::from_utf8(s).unwrap()
+        let mut s = String::new();
+        self.fmt_default(&mut s, &French).unwrap();
+        s
     }
 
     pub fn to_string_traditional(&self) -> String {
-        let mut s = Vec::new();
-        self.fmt_traditional(&mut s).unwrap();
-        String::from_utf8(s).unwrap()
+        let mut s = String::new();
+        self.fmt_traditional(&mut s, &French).unwrap();
+        s
+    }
+}
+
+/// Formats as `Primidi 1 Vendémiaire 1`, or as `Primidi 1 Vendémiaire an I` with the alternate flag (`{:#}`).
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.localized(&French), f)
+    }
+}
+
+impl std::fmt::Display for Localized<'_, Date> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            self.value.fmt_traditional(f, self.locale)
+        } else {
+            self.value.fmt_default(f, self.locale)
+        }
+    }
+}
+
+/// Parses both forms of [`Display`](std::fmt::Display).
+/// The name of the day of the decade is optional, but must match the date if present.
+impl std::str::FromStr for Date {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, rest) = parse_date(s)?;
+        if !rest.trim().is_empty() {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(date)
+    }
+}
+
+/// Parses a date written in one of the forms of `Display`, and returns the rest of the input.
+pub(crate) fn parse_date(s: &str) -> Result<(Date, &str), Error> {
+    let mut tokens = s.split_whitespace().peekable();
+
+    let mut decade_day = Vec::new();
+    while let Some(token) = tokens.next_if(|token| token.parse::<i64>().is_err()) {
+        decade_day.push(token);
+    }
+    let day = tokens
+        .next()
+        .ok_or(Error::InvalidFormat)?
+        .parse::<i64>()
+        .map_err(|_| Error::InvalidFormat)?;
+    let month = tokens.next().ok_or(Error::InvalidFormat)?;
+    let month = (1..=13)
+        .map(Month::from_num)
+        .find(|m| m.name().to_lowercase() == month.to_lowercase())
+        .ok_or(Error::InvalidFormat)?;
+    let offset = |token: &str| token.as_ptr() as usize - s.as_ptr() as usize;
+    let (year, rest) = match tokens.next().ok_or(Error::InvalidFormat)? {
+        "an" => {
+            let roman = tokens.next().ok_or(Error::InvalidFormat)?;
+            roman::parse_roman_year(&s[offset(roman)..]).ok_or(Error::InvalidFormat)?
+        }
+        year => {
+            let rest = &s[offset(year) + year.len()..];
+            (year.parse::<i64>().map_err(|_| Error::InvalidFormat)?, rest)
+        }
+    };
+
+    let date = Date::try_from_ymd(year, month.num(), day)?;
+    if !decade_day.is_empty()
+        && decade_day.join(" ").to_lowercase() != date.decade_day().name().to_lowercase()
+    {
+        return Err(Error::DecadeDayMismatch);
+    }
+    Ok((date, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let date = Date::from_ymd(233, 2, 11);
+        assert_eq!(date.to_string(), "Primidi 11 Brumaire 233");
+        assert_eq!(format!("{date:#}"), "Primidi 11 Brumaire an CCXXXIII");
+        assert_eq!(date.to_string(), date.to_string_default());
+        assert_eq!(format!("{date:#}"), date.to_string_traditional());
+        assert_eq!(Date::from_ymd(-5, 1, 1).to_string(), "Primidi 1 Vendémiaire -5");
+        assert_eq!(
+            format!("{:#}", Date::from_ymd(-5, 1, 1)),
+            "Primidi 1 Vendémiaire an V avant la République"
+        );
+    }
+
+    #[test]
+    fn test_franciade() {
+        // Years 1 and 2 used to be in the franciade -1, unlike with DateTime
+        for (year, franciade) in [(-5, -1), (-2, -1), (-1, 1), (1, 1), (2, 1), (3, 1), (4, 2)] {
+            let date = Date::from_ymd(year, 1, 1);
+            assert_eq!(date.franciade(), franciade);
+            assert_eq!(franciade, DateTime::from_ymd(year, 1, 1).franciade());
+        }
+        assert_eq!(Date::from_ymd(1, 1, 1).franciade0(), 0);
+    }
+
+    #[test]
+    fn test_from_str() {
+        for date in [
+            Date::from_ymd(233, 2, 11),
+            Date::from_ymd(3, 13, 6),
+            Date::from_ymd(1999, 12, 30),
+            Date::from_ymd(-5, 1, 1),
+        ] {
+            assert_eq!(date.to_string().parse::<Date>(), Ok(date.clone()));
+            assert_eq!(format!("{date:#}").parse::<Date>(), Ok(date));
+        }
+        assert_eq!("11 brumaire an IV".parse(), Ok(Date::from_ymd(4, 2, 11)));
+        assert_eq!("Primidi 11 Brumaire 233 extra".parse::<Date>(), Err(Error::InvalidFormat));
+        assert_eq!("Duodi 11 Brumaire 233".parse::<Date>(), Err(Error::DecadeDayMismatch));
+        assert_eq!("Primidi 11 Brumidor 233".parse::<Date>(), Err(Error::InvalidFormat));
+        assert_eq!("6 Sansculotides 1".parse::<Date>(), Err(Error::SansculottideDayOutOfRange));
+        assert_eq!("11 Brumaire an IIX".parse::<Date>(), Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_sansculottides() {
+        assert!(Date::try_from_ymd(3, 13, 6).is_ok()); // sextile
+        assert_eq!(
+            Date::try_from_ymd(1, 13, 6),
+            Err(Error::SansculottideDayOutOfRange)
+        );
+        assert_eq!(
+            Date::try_from_ymd(1, 13, 30),
+            Err(Error::SansculottideDayOutOfRange)
+        );
+        assert_eq!(
+            Date::try_from_ymd0(-1, 12, 5),
+            Err(Error::SansculottideDayOutOfRange)
+        );
+        assert_eq!(
+            DateTime::try_from_ymd(1, 13, 6),
+            Err(Error::SansculottideDayOutOfRange)
+        );
+
+        assert_eq!(Date::from_ymd_normalized(1, 13, 6), Date::from_ymd(2, 1, 1));
+        assert_eq!(Date::from_ymd_normalized(1, 13, 30), Date::from_ymd(2, 1, 25));
+        assert_eq!(Date::from_ymd_normalized(3, 13, 6), Date::from_ymd(3, 13, 6));
+        assert_eq!(Date::from_ymd_normalized(-1, 13, 7), Date::from_ymd(1, 1, 2));
+        assert_eq!(Date::try_from_ymd_normalized(0, 13, 7), Err(Error::YearZero));
+
+        for year in 1..100 {
+            let day_count = get_day_count(year) - 360;
+            let last = Date::from_ymd(year, 13, day_count);
+            assert_eq!(last.decade_day().num(), day_count);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "sansculottide day is beyond the end of the year")]
+    fn test_sansculottides_panic() {
+        Date::from_ymd(1, 13, 6);
     }
 }
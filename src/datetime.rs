@@ -8,12 +8,21 @@ pub struct DateTime {
     hour: i64,
     minute: i64,
     second: i64,
+    nanosecond: i64,
 }
 
 impl DateTime {
     pub fn from_timestamp(timestamp: Timestamp) -> Self {
-        let year0 = ts_to_year0(timestamp.seconds);
-        let seconds_in_year = timestamp.seconds - get_year_start0(year0);
+        Self::from_timestamp_with(timestamp, &Imcce)
+    }
+
+    /// Same as [`DateTime::from_timestamp`] with an explicit [`YearStartProvider`].
+    pub fn from_timestamp_with<P: YearStartProvider + ?Sized>(
+        timestamp: Timestamp,
+        provider: &P,
+    ) -> Self {
+        let year0 = ts_to_year0_with(timestamp.seconds, provider);
+        let seconds_in_year = (timestamp.seconds as i128 - provider.year_start0(year0)) as i64;
 
         let month0 = seconds_in_year.div_euclid(SECONDS_PER_MONTH);
         let seconds_in_month = seconds_in_year.rem_euclid(SECONDS_PER_MONTH);
@@ -21,11 +30,11 @@ impl DateTime {
         let day0 = seconds_in_month.div_euclid(SECONDS_PER_DAY);
         let seconds_in_day = seconds_in_month.rem_euclid(SECONDS_PER_DAY);
 
-        let hour = seconds_in_day.div_euclid(10000);
-        let seconds_in_hour = seconds_in_day.rem_euclid(10000);
+        let hour = seconds_in_day.div_euclid(SECONDS_PER_HOUR);
+        let seconds_in_hour = seconds_in_day.rem_euclid(SECONDS_PER_HOUR);
 
-        let minute = seconds_in_hour.div_euclid(100);
-        let second = seconds_in_hour.rem_euclid(100);
+        let minute = seconds_in_hour.div_euclid(SECONDS_PER_MINUTE);
+        let second = seconds_in_hour.rem_euclid(SECONDS_PER_MINUTE);
 
         Self {
             year0,
@@ -34,6 +43,7 @@ impl DateTime {
             hour,
             minute,
             second,
+            nanosecond: timestamp.nanos as i64,
         }
     }
 
@@ -45,7 +55,12 @@ impl DateTime {
     /// - day is not in [1, 30],
     /// - hour is not in [0, 9],
     /// - minute is not in [0, 99],
-    /// - second is not in [0, 99].
+    /// - second is not in [0, 99],
+    /// - the Sansculottide day is beyond the end of the year (day 6 only exists in sextile years),
+    /// - the date cannot be represented by a [`Timestamp`].
+    ///
+    /// See [`DateTime::try_from_ymd_hms`] for a non-panicking version,
+    /// and [`DateTime::from_ymd_hms_normalized`] to roll over to the next year.
     pub fn from_ymd_hms(
         year: i64,
         month: i64,
@@ -54,26 +69,8 @@ impl DateTime {
         minute: i64,
         second: i64,
     ) -> Self {
-        let year0 = match year.cmp(&0) {
-            std::cmp::Ordering::Greater => year - 1,
-            std::cmp::Ordering::Less => year,
-            std::cmp::Ordering::Equal => panic!("year cannot be 0"),
-        };
-        assert!((1..=13).contains(&month), "month must be in [1, 13]");
-        assert!((1..=30).contains(&day), "day must be in [1, 30]");
-        assert!((0..=9).contains(&hour), "hour must be in [0, 9]");
-        assert!((0..=99).contains(&minute), "minute must be in [0, 99]");
-        assert!((0..=99).contains(&second), "second must be in [0, 99]");
-        let month0 = month - 1;
-        let day0 = day - 1;
-        Self {
-            year0,
-            month0,
-            day0,
-            hour,
-            minute,
-            second,
-        }
+        Self::try_from_ymd_hms(year, month, day, hour, minute, second)
+            .unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// # Panics
@@ -83,7 +80,11 @@ impl DateTime {
     /// - day is not in [0, 29],
     /// - hour is not in [0, 9],
     /// - minute is not in [0, 99],
-    /// - second is not in [0, 99].
+    /// - second is not in [0, 99],
+    /// - the Sansculottide day is beyond the end of the year (day 5 only exists in sextile years),
+    /// - the date cannot be represented by a [`Timestamp`].
+    ///
+    /// See [`DateTime::try_from_ymd_hms0`] for a non-panicking version.
     pub fn from_ymd_hms0(
         year0: i64,
         month0: i64,
@@ -92,19 +93,8 @@ impl DateTime {
         minute: i64,
         second: i64,
     ) -> Self {
-        assert!((0..=12).contains(&month0), "month must be in [0, 12]");
-        assert!((0..=29).contains(&day0), "day must be in [0, 29]");
-        assert!((0..=9).contains(&hour), "hour must be in [0, 9]");
-        assert!((0..=99).contains(&minute), "minute must be in [0, 99]");
-        assert!((0..=99).contains(&second), "second must be in [0, 99]");
-        Self {
-            year0,
-            month0,
-            day0,
-            hour,
-            minute,
-            second,
-        }
+        Self::try_from_ymd_hms0(year0, month0, day0, hour, minute, second)
+            .unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// # Panics
@@ -112,7 +102,11 @@ impl DateTime {
     /// Panics if:
     /// - year is 0,
     /// - month is not in [1, 13],
-    /// - day is not in [1, 30].
+    /// - day is not in [1, 30],
+    /// - the Sansculottide day is beyond the end of the year (day 6 only exists in sextile years),
+    /// - the date cannot be represented by a [`Timestamp`].
+    ///
+    /// See [`DateTime::try_from_ymd`] for a non-panicking version.
     pub fn from_ymd(year: i64, month: i64, day: i64) -> Self {
         Self::from_ymd_hms(year, month, day, 0, 0, 0)
     }
@@ -121,22 +115,181 @@ impl DateTime {
     ///
     /// Panics if:
     /// - month is not in [0, 12],
-    /// - day is not in [0, 29].
+    /// - day is not in [0, 29],
+    /// - the Sansculottide day is beyond the end of the year (day 5 only exists in sextile years),
+    /// - the date cannot be represented by a [`Timestamp`].
+    ///
+    /// See [`DateTime::try_from_ymd0`] for a non-panicking version.
     pub fn from_ymd0(year0: i64, month0: i64, day0: i64) -> Self {
         Self::from_ymd_hms0(year0, month0, day0, 0, 0, 0)
     }
 
+    /// Same as [`DateTime::from_ymd_hms`] but returns an error instead of panicking.
+    pub fn try_from_ymd_hms(
+        year: i64,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Result<Self, Error> {
+        Self::try_from_ymd_hms_with(year, month, day, hour, minute, second, &Imcce)
+    }
+
+    /// Same as [`DateTime::try_from_ymd_hms`] with an explicit [`YearStartProvider`], which decides whether the year is sextile.
+    pub fn try_from_ymd_hms_with<P: YearStartProvider + ?Sized>(
+        year: i64,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        let date = Date::try_from_ymd_with(year, month, day, provider)?;
+        Self::try_from_date_hms(date, hour, minute, second, provider)
+    }
+
+    /// Same as [`DateTime::from_ymd_hms0`] but returns an error instead of panicking.
+    pub fn try_from_ymd_hms0(
+        year0: i64,
+        month0: i64,
+        day0: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Result<Self, Error> {
+        let date = Date::try_from_ymd0(year0, month0, day0)?;
+        Self::try_from_date_hms(date, hour, minute, second, &Imcce)
+    }
+
+    fn try_from_date_hms<P: YearStartProvider + ?Sized>(
+        date: Date,
+        hour: i64,
+        minute: i64,
+        second: i64,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        if !(0..=9).contains(&hour) {
+            return Err(Error::HourOutOfRange);
+        }
+        if !(0..=99).contains(&minute) {
+            return Err(Error::MinuteOutOfRange);
+        }
+        if !(0..=99).contains(&second) {
+            return Err(Error::SecondOutOfRange);
+        }
+        let datetime = Self {
+            year0: date.year0(),
+            month0: date.num_month0(),
+            day0: date.day0(),
+            hour,
+            minute,
+            second,
+            nanosecond: 0,
+        };
+        datetime
+            .checked_timestamp_with(provider)
+            .ok_or(Error::TimestampOutOfRange)?;
+        Ok(datetime)
+    }
+
+    /// Same as [`DateTime::from_ymd_hms`], but Sansculottide days beyond the end of the year roll over to the next year.
+    /// See [`Date::from_ymd_normalized`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - year is 0,
+    /// - month is not in [1, 13],
+    /// - day is not in [1, 30],
+    /// - hour is not in [0, 9],
+    /// - minute is not in [0, 99],
+    /// - second is not in [0, 99],
+    /// - the date cannot be represented by a [`Timestamp`].
+    pub fn from_ymd_hms_normalized(
+        year: i64,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Self {
+        Self::try_from_ymd_hms_normalized(year, month, day, hour, minute, second)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Same as [`DateTime::from_ymd_hms_normalized`] but returns an error instead of panicking.
+    pub fn try_from_ymd_hms_normalized(
+        year: i64,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Result<Self, Error> {
+        let date = Date::try_from_ymd_normalized(year, month, day)?;
+        Self::try_from_ymd_hms0(
+            date.year0(),
+            date.num_month0(),
+            date.day0(),
+            hour,
+            minute,
+            second,
+        )
+    }
+
+    /// Same as [`DateTime::from_ymd`] but returns an error instead of panicking.
+    pub fn try_from_ymd(year: i64, month: i64, day: i64) -> Result<Self, Error> {
+        Self::try_from_ymd_hms(year, month, day, 0, 0, 0)
+    }
+
+    /// Same as [`DateTime::from_ymd0`] but returns an error instead of panicking.
+    pub fn try_from_ymd0(year0: i64, month0: i64, day0: i64) -> Result<Self, Error> {
+        Self::try_from_ymd_hms0(year0, month0, day0, 0, 0, 0)
+    }
+
+    /// Same as [`DateTime::from_ymd_hms`] but returns `None` instead of panicking.
+    pub fn checked_from_ymd_hms(
+        year: i64,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Option<Self> {
+        Self::try_from_ymd_hms(year, month, day, hour, minute, second).ok()
+    }
+
+    /// Same as [`DateTime::from_ymd_hms0`] but returns `None` instead of panicking.
+    pub fn checked_from_ymd_hms0(
+        year0: i64,
+        month0: i64,
+        day0: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+    ) -> Option<Self> {
+        Self::try_from_ymd_hms0(year0, month0, day0, hour, minute, second).ok()
+    }
+
+    /// Same as [`DateTime::from_ymd`] but returns `None` instead of panicking.
+    pub fn checked_from_ymd(year: i64, month: i64, day: i64) -> Option<Self> {
+        Self::checked_from_ymd_hms(year, month, day, 0, 0, 0)
+    }
+
+    /// Same as [`DateTime::from_ymd0`] but returns `None` instead of panicking.
+    pub fn checked_from_ymd0(year0: i64, month0: i64, day0: i64) -> Option<Self> {
+        Self::checked_from_ymd_hms0(year0, month0, day0, 0, 0, 0)
+    }
+
     /// Returns the franciade number starting from 0.
     ///
     /// A franciade is defined as 4 years, the first franciade ending in year 3.
     /// It is *not* defined as a period of years ending with a sextile year.
     /// Not all franciades are `365*4+1` days long.
     pub fn franciade0(&self) -> i64 {
-        if self.year0 >= 0 {
-            (self.year0 + 1) / 4
-        } else {
-            (self.year0 - 2) / 4
-        }
+        franciade0_of_year0(self.year0)
     }
 
     /// Returns the franciade number starting from 1.
@@ -145,12 +298,7 @@ impl DateTime {
     /// It is *not* defined as a period of years ending with a sextile year.
     /// Not all franciades are `365*4+1` days long.
     pub fn franciade(&self) -> i64 {
-        let franciade0 = self.franciade0();
-        if franciade0 >= 0 {
-            franciade0 + 1
-        } else {
-            franciade0
-        }
+        republican_year(self.franciade0())
     }
 
     /// Returns the year but starting from 0.
@@ -199,6 +347,21 @@ impl DateTime {
         day_name(self.month(), self.day())
     }
 
+    /// Returns the season of the date, or `None` during the Sansculotides.
+    pub fn season(&self) -> Option<Season> {
+        self.month().season()
+    }
+
+    /// Returns the feast of the day, with its article, gender, number and kind.
+    pub fn feast(&self) -> Feast {
+        Feast::new(self.month(), self.day())
+    }
+
+    /// Returns the name of the day in a [`Locale`], or in French if the locale has no glossary of the feasts.
+    pub fn day_name_in<'a>(&self, locale: &'a dyn Locale) -> &'a str {
+        day_name_in(locale, self.month(), self.day())
+    }
+
     /// Returns the name of the day preceded by the appropriate article
     pub fn day_name_with_article(&self) -> &'static str {
         day_name_with_article(self.month(), self.day())
@@ -244,116 +407,198 @@ impl DateTime {
         self.second
     }
 
+    /// Returns the number of nanoseconds elapsed since the beginning of the current decimal second.
+    pub fn nanosecond(&self) -> i64 {
+        self.nanosecond
+    }
+
+    /// Returns the same date and time with another number of nanoseconds.
+    pub(crate) fn try_with_nanosecond(mut self, nanosecond: i64) -> Result<Self, Error> {
+        if !(0..1_000_000_000).contains(&nanosecond) {
+            return Err(Error::NanosecondOutOfRange);
+        }
+        self.nanosecond = nanosecond;
+        Ok(self)
+    }
+
     pub fn hms(&self) -> (i64, i64, i64) {
         (self.hour, self.minute, self.second)
     }
 
     /// Returns the timestamp
     pub fn timestamp(&self) -> Timestamp {
-        Timestamp {
-            seconds: get_year_start0(self.year0)
-                + self.month0 * SECONDS_PER_MONTH
+        self.timestamp_with(&Imcce)
+    }
+
+    /// Same as [`DateTime::timestamp`] with an explicit [`YearStartProvider`].
+    pub fn timestamp_with<P: YearStartProvider + ?Sized>(&self, provider: &P) -> Timestamp {
+        self.checked_timestamp_with(provider)
+            .expect("date cannot be represented by a timestamp")
+    }
+
+    /// Returns the timestamp, or `None` if it would overflow.
+    pub fn checked_timestamp(&self) -> Option<Timestamp> {
+        self.checked_timestamp_with(&Imcce)
+    }
+
+    /// Same as [`DateTime::checked_timestamp`] with an explicit [`YearStartProvider`].
+    pub fn checked_timestamp_with<P: YearStartProvider + ?Sized>(
+        &self,
+        provider: &P,
+    ) -> Option<Timestamp> {
+        let year_start = i64::try_from(provider.year_start0(self.year0)).ok()?;
+        let seconds = year_start.checked_add(
+            self.month0 * SECONDS_PER_MONTH
                 + self.day0 * SECONDS_PER_DAY
-                + self.hour * 10000
-                + self.minute * 100
+                + self.hour * SECONDS_PER_HOUR
+                + self.minute * SECONDS_PER_MINUTE
                 + self.second,
-        }
+        )?;
+        Some(Timestamp {
+            seconds,
+            nanos: self.nanosecond as u32,
+        })
     }
 
-    fn fmt_default(&self, f: &mut impl std::io::Write) -> std::io::Result<()> {
-        write!(
-            f,
-            "{} {} {} {}",
-            self.decade_day(),
-            self.day(),
-            self.month(),
-            self.year(),
-        )
+    /// Returns the date, without the time.
+    pub fn date(&self) -> Date {
+        Date::from_ymd0_unchecked(self.year0, self.month0, self.day0)
     }
 
-    fn fmt_traditional(&self, f: &mut impl std::io::Write) -> std::io::Result<()> {
-        let mut remaining_years = self.year();
-        let thousand_years = remaining_years.div_euclid(1000);
-        remaining_years -= thousand_years * 1000;
-        let thousand_years = "M".repeat(thousand_years as usize);
-        let five_hundred_years = remaining_years.div_euclid(500);
-        remaining_years -= five_hundred_years * 500;
-        let five_hundred_years = match five_hundred_years {
-            0 => "",
-            1 => "D",
-            _ => unreachable!(),
-        };
-        let hundred_years = remaining_years.div_euclid(100);
-        remaining_years -= hundred_years * 100;
-        let hundred_years = match hundred_years {
-            0 => "",
-            1 => "C",
-            2 => "CC",
-            3 => "CCC",
-            4 => "CD",
-            _ => unreachable!(),
-        };
-        let fifty_years = remaining_years.div_euclid(50);
-        remaining_years -= fifty_years * 50;
-        let fifty_years = match fifty_years {
-            0 => "",
-            1 => "L",
-            _ => unreachable!(),
-        };
-        let ten_years = remaining_years.div_euclid(10);
-        remaining_years -= ten_years * 10;
-        let ten_years = match ten_years {
-            0 => "",
-            1 => "X",
-            2 => "XX",
-            3 => "XXX",
-            4 => "XL",
-            _ => unreachable!(),
-        };
-        let five_years = remaining_years.div_euclid(5);
-        remaining_years -= five_years * 5;
-        let five_years = match five_years {
-            0 => "",
-            1 => "V",
-            _ => unreachable!(),
-        };
-        let one_year = remaining_years;
-        let one_year = match one_year {
-            0 => "",
-            1 => "I",
-            2 => "II",
-            3 => "III",
-            4 => "IV",
-            _ => unreachable!(),
-        };
+    /// Formats the date and time with a `strftime`-like pattern, see the [`format` module](crate::format) for the specifiers.
+    pub fn format<'a>(&self, pattern: &'a str) -> DelayedFormat<'a> {
+        self.format_with(pattern, &French)
+    }
+
+    /// Same as [`DateTime::format`] with the names of a [`Locale`].
+    pub fn format_with<'a>(&self, pattern: &'a str, locale: &'a dyn Locale) -> DelayedFormat<'a> {
+        let time = (self.hour, self.minute, self.second, self.nanosecond);
+        DelayedFormat::new(self.date(), Some(time), pattern, locale)
+    }
+
+    /// Returns a [`Display`](std::fmt::Display) implementation with the names of a [`Locale`].
+    pub fn localized<'a>(&'a self, locale: &'a dyn Locale) -> Localized<'a, Self> {
+        Localized::new(self, locale)
+    }
 
+    /// Parses a date and time with a pattern of the [`format` module](crate::format), such as `%A %d %B an %R %H:%M:%S`.
+    ///
+    /// Names are matched regardless of case and accents, years can be written in arabic or roman numerals.
+    /// The time is optional: if the input ends before the first time specifier, the time is midnight.
+    pub fn parse_from_str(s: &str, pattern: &str) -> Result<Self, Error> {
+        let parsed = format::parse(s, pattern)?;
+        let date = parsed.to_date()?;
+        DateTime::try_from_ymd_hms0(
+            date.year0(),
+            date.num_month0(),
+            date.day0(),
+            parsed.hour,
+            parsed.minute,
+            parsed.second,
+        )?
+        .try_with_nanosecond(parsed.nanosecond)
+    }
+
+    fn fmt_default(&self, f: &mut impl std::fmt::Write, locale: &dyn Locale) -> std::fmt::Result {
+        write!(f, "{}", self.format_with("%A %-d %B %Y", locale))
+    }
+
+    fn fmt_traditional(
+        &self,
+        f: &mut impl std::fmt::Write,
+        locale: &dyn Locale,
+    ) -> std::fmt::Result {
         write!(
             f,
-            "{} {} {} an {}{}{}{}{}{}{}",
-            self.decade_day(),
-            self.day(),
-            self.month(),
-            thousand_years,
-            five_hundred_years,
-            hundred_years,
-            fifty_years,
-            ten_years,
-            five_years,
-            one_year
+            "{} {} {}",
+            self.format_with("%A %-d %B", locale),
+            locale.year_word(),
+            self.format_with("%R", locale)
         )
     }
 
     pub fn to_string_default(&self) -> String {
-        let mut s = Vec::new();
-        self.fmt_default(&mut s).unwrap();
-        String::from_utf8(s).unwrap()
+        let mut s = String::new();
+        self.fmt_default(&mut s, &French).unwrap();
+        s
     }
 
     pub fn to_string_traditional(&self) -> String {
-        let mut s = Vec::new();
-        self.fmt_traditional(&mut s).unwrap();
-        String::from_utf8(s).unwrap()
+        let mut s = String::new();
+        self.fmt_traditional(&mut s, &French).unwrap();
+        s
+    }
+
+    fn fmt_time(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        write!(f, " {}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.nanosecond != 0 {
+            write!(f, ".{:09}", self.nanosecond)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats as `Primidi 1 Vendémiaire 1 3:87:84`, or as `Primidi 1 Vendémiaire an I 3:87:84` with the alternate flag (`{:#}`).
+/// Nanoseconds are only shown if they are not zero.
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.localized(&French), f)
+    }
+}
+
+impl std::fmt::Display for Localized<'_, DateTime> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            self.value.fmt_traditional(f, self.locale)?;
+        } else {
+            self.value.fmt_default(f, self.locale)?;
+        }
+        self.value.fmt_time(f)
+    }
+}
+
+/// Parses both forms of [`Display`](std::fmt::Display).
+/// The time is optional and defaults to midnight.
+impl std::str::FromStr for DateTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, rest) = parse_date(s)?;
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return DateTime::try_from_ymd(date.year(), date.num_month(), date.day());
+        }
+
+        let (time, nanosecond) = match rest.split_once('.') {
+            Some((time, nanos)) if !nanos.is_empty() && nanos.len() <= 9 => {
+                (time, parse_digits(nanos)? * 10i64.pow(9 - nanos.len() as u32))
+            }
+            Some(_) => return Err(Error::InvalidFormat),
+            None => (rest, 0),
+        };
+        let mut parts = time.split(':');
+        let (Some(hour), Some(minute), Some(second), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(Error::InvalidFormat);
+        };
+        DateTime::try_from_ymd_hms(
+            date.year(),
+            date.num_month(),
+            date.day(),
+            parse_digits(hour)?,
+            parse_digits(minute)?,
+            parse_digits(second)?,
+        )?
+        .try_with_nanosecond(nanosecond)
+    }
+}
+
+fn parse_digits(s: &str) -> Result<i64, Error> {
+    if s.is_empty() || s.len() > 9 || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidFormat);
     }
+    s.parse().map_err(|_| Error::InvalidFormat)
 }
 
 #[cfg(test)]
@@ -363,70 +608,221 @@ mod tests {
 
     #[test]
     fn test_franciade() {
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: 0 });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(0));
         assert_eq!(datetime.franciade0(), 0);
         assert_eq!(datetime.franciade(), 1);
         
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: -400*SECONDS_PER_DAY });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(-400*SECONDS_PER_DAY));
         assert_eq!(datetime.franciade0(), -1);
         assert_eq!(datetime.franciade(), -1);
 
-        let datetime = DateTime::from_timestamp(Timestamp {
-            seconds: SECONDS_PER_YEAR * 5,
-        });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(SECONDS_PER_YEAR * 5));
         assert_eq!(datetime.franciade0(), 1);
         assert_eq!(datetime.franciade(), 2);
     }
 
     #[test]
     fn test_year() {
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: 0 });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(0));
         assert_eq!(datetime.year0(), 0);
         assert_eq!(datetime.year(), 1);
 
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: -1 });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(-1));
         assert_eq!(datetime.year0(), -1);
         assert_eq!(datetime.year(), -1);
 
-        let datetime = DateTime::from_timestamp(Timestamp {
-            seconds: -SECONDS_PER_YEAR - SECONDS_PER_DAY - 1,
-        });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(
+            -SECONDS_PER_YEAR - SECONDS_PER_DAY - 1,
+        ));
         assert_eq!(datetime.year0(), -2);
         assert_eq!(datetime.year(), -2);
     }
 
     #[test]
     fn test_month() {
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: 0 });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(0));
         assert_eq!(datetime.num_month0(), 0);
         assert_eq!(datetime.month(), Month::Vendémiaire);
 
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: -1 });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(-1));
         assert_eq!(datetime.num_month0(), 12);
         assert_eq!(datetime.month(), Month::Sansculotides);
     }
 
     #[test]
     fn test_day() {
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: 0 });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(0));
         assert_eq!(datetime.day0(), 0);
         assert_eq!(datetime.day(), 1);
 
-        let datetime = DateTime::from_timestamp(Timestamp {
-            seconds: get_year_start(4) - 1,
-        });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(get_year_start(4) - 1));
         assert_eq!(datetime.day0(), 5); // Jour de la révolution
         assert_eq!(datetime.decade_day().name(), "Jour de la Révolution");
         assert_eq!(datetime.day(), 6);
     }
 
+    #[test]
+    fn test_errors() {
+        assert_eq!(DateTime::try_from_ymd(0, 1, 1), Err(Error::YearZero));
+        assert_eq!(DateTime::try_from_ymd(1, 0, 1), Err(Error::MonthOutOfRange));
+        assert_eq!(DateTime::try_from_ymd(1, 1, 31), Err(Error::DayOutOfRange));
+        assert_eq!(
+            DateTime::try_from_ymd_hms(1, 1, 1, 10, 0, 0),
+            Err(Error::HourOutOfRange)
+        );
+        assert_eq!(
+            DateTime::try_from_ymd_hms(1, 1, 1, 0, 100, 0),
+            Err(Error::MinuteOutOfRange)
+        );
+        assert_eq!(
+            DateTime::try_from_ymd_hms0(0, 0, 0, 0, 0, -1),
+            Err(Error::SecondOutOfRange)
+        );
+        assert_eq!(
+            Date::try_from_ymd(i64::MAX, 1, 1),
+            Err(Error::TimestampOutOfRange)
+        );
+        assert_eq!(Date::try_from_ymd0(0, 13, 0), Err(Error::Month0OutOfRange));
+        assert_eq!(Date::try_from_ymd0(0, 0, 30), Err(Error::Day0OutOfRange));
+        assert_eq!(Date::try_from_ymd(1, 14, 1), Err(Error::MonthOutOfRange));
+        assert_eq!(Date::try_from_ymd(1, i64::MIN, 1), Err(Error::MonthOutOfRange));
+        assert_eq!(
+            DateTime::try_from_ymd0(0, -1, 0),
+            Err(Error::Month0OutOfRange)
+        );
+        assert_eq!(
+            Error::MonthOutOfRange.to_string(),
+            "month must be in [1, 13]"
+        );
+        assert_eq!(
+            Error::Day0OutOfRange.to_string(),
+            "day0 must be in [0, 29]"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "day must be in [1, 30]")]
+    fn test_panic_message() {
+        DateTime::from_ymd(1, 1, 31);
+    }
+
+    #[test]
+    fn test_normalized() {
+        assert_eq!(
+            DateTime::from_ymd_hms_normalized(1, 13, 6, 5, 0, 0),
+            DateTime::from_ymd_hms(2, 1, 1, 5, 0, 0)
+        );
+        assert_eq!(
+            DateTime::try_from_ymd_hms_normalized(1, 13, 6, 10, 0, 0),
+            Err(Error::HourOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_checked() {
+        assert!(DateTime::checked_from_ymd(0, 1, 1).is_none());
+        assert!(DateTime::checked_from_ymd(1, 14, 1).is_none());
+        assert!(DateTime::checked_from_ymd_hms(1, 1, 1, 10, 0, 0).is_none());
+        assert!(DateTime::checked_from_ymd(i64::MAX, 1, 1).is_none());
+        assert!(DateTime::checked_from_ymd(i64::MIN, 1, 1).is_none());
+        assert!(Date::checked_from_ymd(i64::MAX, 1, 1).is_none());
+        assert!(Date::checked_from_ymd(1, i64::MIN, 1).is_none());
+        assert_eq!(
+            DateTime::checked_from_ymd(1, 1, 1),
+            Some(DateTime::from_ymd(1, 1, 1))
+        );
+
+        let last = DateTime::from_timestamp(Timestamp::from_seconds(i64::MAX));
+        assert_eq!(last.timestamp(), Timestamp::from_seconds(i64::MAX));
+        let first = Date::from_timestamp(Timestamp::from_seconds(i64::MIN));
+        assert!(first.checked_timestamp().is_none());
+        let first = DateTime::from_timestamp(Timestamp::from_seconds(i64::MIN));
+        assert_eq!(first.date().year0(), first.year0());
+        assert!(first.to_string().contains(&format!(" {} ", first.year())));
+    }
+
+    #[test]
+    fn test_provider() {
+        let ts = Timestamp::from_seconds(get_year_start(-3000) + 1234567);
+        let datetime = DateTime::from_timestamp_with(ts, &Astronomical);
+        assert_eq!(datetime.timestamp_with(&Astronomical), ts);
+        assert_eq!(datetime.year(), ts_to_year_with(ts.seconds, &Astronomical));
+
+        let date = Date::from_timestamp_with(ts, &Imcce);
+        assert_eq!(date, Date::from_timestamp(ts));
+        assert_eq!(date.checked_timestamp_with(&Imcce), date.checked_timestamp());
+
+        // The year 19 is sextile in the arithmetic calendar, but not with the IMCCE equinoxes
+        let start = checked_get_year_start_with(20, &Arithmetic).unwrap();
+        let ts = Timestamp::from_seconds(start - SECONDS_PER_DAY);
+        let datetime = DateTime::from_timestamp_with(ts, &Arithmetic);
+        assert_eq!((datetime.year(), datetime.day()), (19, 6));
+        assert_eq!(datetime.to_string(), "Jour de la Révolution 6 Sansculotides 19 0:00:00");
+        assert_eq!(datetime.date().day(), 6);
+        assert_eq!(
+            DateTime::try_from_ymd_hms_with(19, 13, 6, 0, 0, 0, &Arithmetic),
+            Ok(datetime)
+        );
+        assert_eq!(
+            DateTime::try_from_ymd(19, 13, 6),
+            Err(Error::SansculottideDayOutOfRange)
+        );
+
+        let date = Date::from_timestamp_with(ts, &Arithmetic);
+        assert_eq!(Date::try_from_ymd_with(19, 13, 6, &Arithmetic), Ok(date.clone()));
+        assert_eq!(Date::try_from_ymd0_with(18, 12, 5, &Arithmetic), Ok(date.clone()));
+        assert_eq!(
+            Date::try_from_ymd_normalized_with(19, 13, 6, &Arithmetic),
+            Ok(date)
+        );
+        assert_eq!(
+            Date::try_from_ymd_normalized(19, 13, 6),
+            Ok(Date::from_ymd(20, 1, 1))
+        );
+        // The year 20 is sextile with the IMCCE equinoxes only
+        assert!(Date::try_from_ymd(20, 13, 6).is_ok());
+        assert_eq!(
+            Date::try_from_ymd_with(20, 13, 6, &Arithmetic),
+            Err(Error::SansculottideDayOutOfRange)
+        );
+    }
+
     #[test]
     fn test_fmt() {
-        let datetime = DateTime::from_timestamp(Timestamp { seconds: 0 });
+        let datetime = DateTime::from_timestamp(Timestamp::from_seconds(0));
         assert_eq!(datetime.to_string_default(), "Primidi 1 Vendémiaire 1");
         assert_eq!(
             datetime.to_string_traditional(),
             "Primidi 1 Vendémiaire an I"
         );
+        assert_eq!(datetime.to_string(), "Primidi 1 Vendémiaire 1 0:00:00");
+        assert_eq!(format!("{datetime:#}"), "Primidi 1 Vendémiaire an I 0:00:00");
+
+        let datetime = DateTime::from_timestamp(Timestamp::from_nanos(38_784_000_000_500));
+        assert_eq!(datetime.to_string(), "Primidi 1 Vendémiaire 1 3:87:84.000000500");
+    }
+
+    #[test]
+    fn test_from_str() {
+        for datetime in [
+            DateTime::from_ymd_hms(233, 2, 11, 3, 87, 84),
+            DateTime::from_ymd_hms(-5, 13, 5, 9, 99, 99),
+            DateTime::from_timestamp(Timestamp::from_nanos(38_784_123_456_789)),
+        ] {
+            assert_eq!(datetime.to_string().parse::<DateTime>(), Ok(datetime.clone()));
+            assert_eq!(format!("{datetime:#}").parse::<DateTime>(), Ok(datetime));
+        }
+        assert_eq!(
+            "11 Brumaire an CCXXXIII".parse::<DateTime>(),
+            Ok(DateTime::from_ymd(233, 2, 11))
+        );
+        assert_eq!(
+            "11 Brumaire 233 1:02:03.5".parse::<DateTime>().map(|d| d.nanosecond()),
+            Ok(500_000_000)
+        );
+        assert_eq!("11 Brumaire 233 10:00:00".parse::<DateTime>(), Err(Error::HourOutOfRange));
+        assert_eq!("11 Brumaire 233 1:00".parse::<DateTime>(), Err(Error::InvalidFormat));
+        assert_eq!("11 Brumaire 233 1:00:00.".parse::<DateTime>(), Err(Error::InvalidFormat));
+        assert_eq!("11 Brumaire 233 +1:00:00".parse::<DateTime>(), Err(Error::InvalidFormat));
     }
 }
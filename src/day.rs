@@ -1,3 +1,6 @@
+use crate::text::fold;
+use crate::{Error, Locale, Localized};
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Day {
     Regular(RegularDay),
@@ -29,6 +32,35 @@ pub enum SansculottideDay {
 }
 
 impl RegularDay {
+    /// All the days of the decade, in order.
+    pub const ALL: [Self; 10] = [
+        Self::Primedi,
+        Self::Duodi,
+        Self::Tridi,
+        Self::Quartidi,
+        Self::Quintidi,
+        Self::Sextidi,
+        Self::Septidi,
+        Self::Octidi,
+        Self::Nonidi,
+        Self::Décadi,
+    ];
+
+    /// Iterates over all the values, in order.
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the next value, wrapping around after the last one.
+    pub fn succ(&self) -> Self {
+        Self::ALL[(self.num0() as usize + 1) % Self::ALL.len()]
+    }
+
+    /// Returns the previous value, wrapping around before the first one.
+    pub fn pred(&self) -> Self {
+        Self::ALL[(self.num0() as usize + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
     pub const fn name(&self) -> &'static str {
         match self {
             Self::Primedi => "Primidi",
@@ -82,9 +114,15 @@ impl RegularDay {
 
     /// # Panics
     ///
-    /// Panics if `num0` is greater than 9.
+    /// Panics if `num0` is not in [0, 9].
     pub fn from_num0(num0: i64) -> Self {
-        match num0 {
+        Self::try_from_num0(num0)
+            .unwrap_or_else(|_| panic!("Invalid regular day number: {}", num0))
+    }
+
+    /// Same as [`RegularDay::from_num0`] but returns an error instead of panicking.
+    pub fn try_from_num0(num0: i64) -> Result<Self, Error> {
+        Ok(match num0 {
             0 => Self::Primedi,
             1 => Self::Duodi,
             2 => Self::Tridi,
@@ -95,16 +133,21 @@ impl RegularDay {
             7 => Self::Octidi,
             8 => Self::Nonidi,
             9 => Self::Décadi,
-            _ => panic!("Invalid regular day number: {}", num0),
-        }
+            _ => return Err(Error::DecadeDayOutOfRange),
+        })
     }
 
     /// # Panics
     ///
-    /// Panics if `num` is greater than 10 or less than 1.
+    /// Panics if `num` is not in [1, 10].
     pub fn from_num(num: i64) -> Self {
         Self::from_num0(num - 1)
     }
+
+    /// Same as [`RegularDay::from_num`] but returns an error instead of panicking.
+    pub fn try_from_num(num: i64) -> Result<Self, Error> {
+        Self::try_from_num0(num.checked_sub(1).ok_or(Error::DecadeDayOutOfRange)?)
+    }
 }
 
 impl std::fmt::Display for RegularDay {
@@ -113,7 +156,62 @@ impl std::fmt::Display for RegularDay {
     }
 }
 
+impl TryFrom<i64> for RegularDay {
+    type Error = Error;
+
+    /// Converts from the number starting from 1, see [`RegularDay::try_from_num`].
+    fn try_from(num: i64) -> Result<Self, Self::Error> {
+        Self::try_from_num(num)
+    }
+}
+
+impl TryFrom<u8> for RegularDay {
+    type Error = Error;
+
+    /// Converts from the number starting from 1, see [`RegularDay::try_from_num`].
+    fn try_from(num: u8) -> Result<Self, Self::Error> {
+        Self::try_from_num(num as i64)
+    }
+}
+
+/// Parses the French name, regardless of case and accents.
+impl std::str::FromStr for RegularDay {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = fold(s.trim());
+        Self::iter()
+            .find(|value| fold(value.name()) == name)
+            .ok_or(Error::InvalidFormat)
+    }
+}
+
 impl SansculottideDay {
+    /// All the Sansculottide days, in order.
+    pub const ALL: [Self; 6] = [
+        Self::Vertu,
+        Self::Génie,
+        Self::Travail,
+        Self::Opinion,
+        Self::Récompenses,
+        Self::Révolution,
+    ];
+
+    /// Iterates over all the values, in order.
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the next value, wrapping around after the last one.
+    pub fn succ(&self) -> Self {
+        Self::ALL[(self.num0() as usize + 1) % Self::ALL.len()]
+    }
+
+    /// Returns the previous value, wrapping around before the first one.
+    pub fn pred(&self) -> Self {
+        Self::ALL[(self.num0() as usize + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
     pub const fn name(&self) -> &'static str {
         match self {
             Self::Vertu => "Jour de la vertu",
@@ -155,25 +253,36 @@ impl SansculottideDay {
 
     /// # Panics
     ///
-    /// Panics if `num0` is greater than 5.
+    /// Panics if `num0` is not in [0, 5].
     pub fn from_num0(num0: i64) -> Self {
-        match num0 {
+        Self::try_from_num0(num0)
+            .unwrap_or_else(|_| panic!("Invalid Sansculottide day number: {}", num0))
+    }
+
+    /// Same as [`SansculottideDay::from_num0`] but returns an error instead of panicking.
+    pub fn try_from_num0(num0: i64) -> Result<Self, Error> {
+        Ok(match num0 {
             0 => Self::Vertu,
             1 => Self::Génie,
             2 => Self::Travail,
             3 => Self::Opinion,
             4 => Self::Récompenses,
             5 => Self::Révolution,
-            _ => panic!("Invalid Sansculottide day number: {}", num0),
-        }
+            _ => return Err(Error::DecadeDayOutOfRange),
+        })
     }
 
     /// # Panics
     ///
-    /// Panics if `num` is greater than 6 or less than 1.
+    /// Panics if `num` is not in [1, 6].
     pub fn from_num(num: i64) -> Self {
         Self::from_num0(num - 1)
     }
+
+    /// Same as [`SansculottideDay::from_num`] but returns an error instead of panicking.
+    pub fn try_from_num(num: i64) -> Result<Self, Error> {
+        Self::try_from_num0(num.checked_sub(1).ok_or(Error::DecadeDayOutOfRange)?)
+    }
 }
 
 impl std::fmt::Display for SansculottideDay {
@@ -182,6 +291,36 @@ impl std::fmt::Display for SansculottideDay {
     }
 }
 
+impl TryFrom<i64> for SansculottideDay {
+    type Error = Error;
+
+    /// Converts from the number starting from 1, see [`SansculottideDay::try_from_num`].
+    fn try_from(num: i64) -> Result<Self, Self::Error> {
+        Self::try_from_num(num)
+    }
+}
+
+impl TryFrom<u8> for SansculottideDay {
+    type Error = Error;
+
+    /// Converts from the number starting from 1, see [`SansculottideDay::try_from_num`].
+    fn try_from(num: u8) -> Result<Self, Self::Error> {
+        Self::try_from_num(num as i64)
+    }
+}
+
+/// Parses the French name, regardless of case and accents.
+impl std::str::FromStr for SansculottideDay {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = fold(s.trim());
+        Self::iter()
+            .find(|value| fold(value.name()) == name)
+            .ok_or(Error::InvalidFormat)
+    }
+}
+
 impl Day {
     pub const fn name(&self) -> &'static str {
         match self {
@@ -190,6 +329,11 @@ impl Day {
         }
     }
 
+    /// Returns a [`Display`](std::fmt::Display) implementation with the name in a [`Locale`].
+    pub fn localized<'a>(&'a self, locale: &'a dyn Locale) -> Localized<'a, Self> {
+        Localized::new(self, locale)
+    }
+
     pub const fn name_lc(&self) -> &'static str {
         match self {
             Self::Regular(day) => day.name_lc(),
@@ -222,3 +366,42 @@ impl std::fmt::Display for Day {
         }
     }
 }
+
+/// Parses the French name of a day of the decade or of a Sansculottide day, regardless of case and accents.
+impl std::str::FromStr for Day {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .map(Day::Regular)
+            .or_else(|_| s.parse().map(Day::Sansculottide))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(RegularDay::try_from(1u8), Ok(RegularDay::Primedi));
+        assert_eq!(RegularDay::try_from(11i64), Err(Error::DecadeDayOutOfRange));
+        assert_eq!(SansculottideDay::try_from(6i64), Ok(SansculottideDay::Révolution));
+        assert_eq!(SansculottideDay::try_from(0u8), Err(Error::DecadeDayOutOfRange));
+
+        assert_eq!("decadi".parse(), Ok(RegularDay::Décadi));
+        assert_eq!(" PRIMIDI ".parse(), Ok(RegularDay::Primedi));
+        assert_eq!("jour du genie".parse(), Ok(SansculottideDay::Génie));
+        assert_eq!("Jour de la Révolution".parse(), Ok(Day::Sansculottide(SansculottideDay::Révolution)));
+        assert_eq!("Tridi".parse(), Ok(Day::Regular(RegularDay::Tridi)));
+        assert_eq!("Undecidi".parse::<Day>(), Err(Error::InvalidFormat));
+
+        assert_eq!(RegularDay::iter().len(), 10);
+        assert!(RegularDay::iter().enumerate().all(|(i, day)| day.num0() == i as i64));
+        assert!(SansculottideDay::iter().enumerate().all(|(i, day)| day.num0() == i as i64));
+        assert_eq!(RegularDay::Décadi.succ(), RegularDay::Primedi);
+        assert_eq!(RegularDay::Primedi.pred(), RegularDay::Décadi);
+        assert_eq!(SansculottideDay::Révolution.succ(), SansculottideDay::Vertu);
+        assert_eq!(SansculottideDay::Génie.pred(), SansculottideDay::Vertu);
+    }
+}
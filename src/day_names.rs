@@ -1,821 +1,35 @@
-use crate::{Month, Month::*};
+use crate::{Feast, Locale, Month};
 
+/// Returns the name of the day in a [`Locale`], or in French if the locale has no glossary of the feasts.
+pub fn day_name_in(locale: &dyn Locale, month: Month, num: i64) -> &str {
+    locale
+        .feast_name(month, num)
+        .unwrap_or_else(|| day_name(month, num))
+}
+
+/// Returns the name of the feast of the day, such as `Raisin`.
+///
+/// # Panics
+///
+/// Panics if the day is not in the month, see [`Feast::try_new`].
 pub fn day_name(month: Month, num: i64) -> &'static str {
-    match month {
-        Vendémiaire => match num {
-            1 => "Raisin",
-            2 => "Safran",
-            3 => "Châtaigne",
-            4 => "Colchique",
-            5 => "Cheval",
-            6 => "Balsamine",
-            7 => "Carotte",
-            8 => "Amarante",
-            9 => "Panais",
-            10 => "Cuve",
-            11 => "Pomme de terre",
-            12 => "Immortelle",
-            13 => "Potiron",
-            14 => "Réséda",
-            15 => "Âne",
-            16 => "Belle-de-nuit",
-            17 => "Citrouille",
-            18 => "Sarrasin",
-            19 => "Tournesol",
-            20 => "Pressoir",
-            21 => "Chanvre",
-            22 => "Pêche",
-            23 => "Navet",
-            24 => "Amaryllis",
-            25 => "Bœuf",
-            26 => "Aubergine",
-            27 => "Piment",
-            28 => "Tomate",
-            29 => "Orge",
-            30 => "Tonneau",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Brumaire => match num {
-            1 => "Pomme",
-            2 => "Céleri",
-            3 => "Poire",
-            4 => "Betterave",
-            5 => "Oie",
-            6 => "Héliotrope",
-            7 => "Figue",
-            8 => "Scorsonère",
-            9 => "Alisier",
-            10 => "Charrue",
-            11 => "Salsifis",
-            12 => "Mâcre",
-            13 => "Topinambour",
-            14 => "Endive",
-            15 => "Dindon",
-            16 => "Chervis",
-            17 => "Cresson",
-            18 => "Dentelaire",
-            19 => "Grenade",
-            20 => "Herse",
-            21 => "Bacchante",
-            22 => "Azerole",
-            23 => "Garance",
-            24 => "Orange",
-            25 => "Faisan",
-            26 => "Pistache",
-            27 => "Macjonc",
-            28 => "Coing",
-            29 => "Cormier",
-            30 => "Rouleau",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Frimaire => match num {
-            1 => "Raiponce",
-            2 => "Turneps",
-            3 => "Chicorée",
-            4 => "Nèfle",
-            5 => "Cochon",
-            6 => "Mâche",
-            7 => "Chou-fleur",
-            8 => "Miel",
-            9 => "Genièvre",
-            10 => "Pioche",
-            11 => "Cire",
-            12 => "Raifort",
-            13 => "Cèdre",
-            14 => "Sapin",
-            15 => "Chevreuil",
-            16 => "Ajonc",
-            17 => "Cyprès",
-            18 => "Lierre",
-            19 => "Sabine",
-            20 => "Hoyau",
-            21 => "Érable sucré",
-            22 => "Bruyère",
-            23 => "Roseau",
-            24 => "Oseille",
-            25 => "Grillon",
-            26 => "Pignon",
-            27 => "Liège",
-            28 => "Truffe",
-            29 => "Olive",
-            30 => "Pelle",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Nivôse => match num {
-            1 => "Tourbe",
-            2 => "Houille",
-            3 => "Bitume",
-            4 => "Soufre",
-            5 => "Chien",
-            6 => "Lave",
-            7 => "Terre végétale",
-            8 => "Fumier",
-            9 => "Salpêtre",
-            10 => "Fléau",
-            11 => "Granit",
-            12 => "Argile",
-            13 => "Ardoise",
-            14 => "Grès",
-            15 => "Lapin",
-            16 => "Silex",
-            17 => "Marne",
-            18 => "Pierre à chaux",
-            19 => "Marbre",
-            20 => "Van",
-            21 => "Pierre à plâtre",
-            22 => "Sel",
-            23 => "Fer",
-            24 => "Cuivre",
-            25 => "Chat",
-            26 => "Étain",
-            27 => "Plomb",
-            28 => "Zinc",
-            29 => "Mercure",
-            30 => "Crible",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Pluviôse => match num {
-            1 => "Lauréole",
-            2 => "Mousse",
-            3 => "Fragon",
-            4 => "Perce neige",
-            5 => "Taureau",
-            6 => "Laurier thym",
-            7 => "Amadouvier",
-            8 => "Mézéréon",
-            9 => "Peuplier",
-            10 => "Cognée",
-            11 => "Ellébore",
-            12 => "Brocoli",
-            13 => "Laurier",
-            14 => "Avelinier",
-            15 => "Vache",
-            16 => "Buis",
-            17 => "Lichen",
-            18 => "If",
-            19 => "Pulmonaire",
-            20 => "Serpette",
-            21 => "Thlaspi",
-            22 => "Thymèle",
-            23 => "Chiendent",
-            24 => "Trainasse",
-            25 => "Lièvre",
-            26 => "Guède",
-            27 => "Noisetier",
-            28 => "Cyclamen",
-            29 => "Chélidoine",
-            30 => "Traîneau",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Ventôse => match num {
-            1 => "Tussilage",
-            2 => "Cornouiller",
-            3 => "Violier",
-            4 => "Troène",
-            5 => "Bouc",
-            6 => "Asaret",
-            7 => "Alaterne",
-            8 => "Violette",
-            9 => "Marceau",
-            10 => "Bêche",
-            11 => "Narcisse",
-            12 => "Orme",
-            13 => "Fumeterre",
-            14 => "Vélar",
-            15 => "Chèvre",
-            16 => "Épinard",
-            17 => "Doronic",
-            18 => "Mouron",
-            19 => "Cerfeuil",
-            20 => "Cordeau",
-            21 => "Mandragore",
-            22 => "Persil",
-            23 => "Cochléaria",
-            24 => "Pâquerette",
-            25 => "Thon",
-            26 => "Pissenlit",
-            27 => "Sylvie",
-            28 => "Capillaire",
-            29 => "Frêne",
-            30 => "Plantoir",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Germinal => match num {
-            1 => "Primevère",
-            2 => "Platane",
-            3 => "Asperge",
-            4 => "Tulipe",
-            5 => "Poule",
-            6 => "Bette",
-            7 => "Bouleau",
-            8 => "Jonquille",
-            9 => "Aulne",
-            10 => "Couvoir",
-            11 => "Pervenche",
-            12 => "Charme",
-            13 => "Morille",
-            14 => "Hêtre",
-            15 => "Abeille",
-            16 => "Laitue",
-            17 => "Mélèze",
-            18 => "Ciguë",
-            19 => "Radis",
-            20 => "Ruche",
-            21 => "Gainier",
-            22 => "Romaine",
-            23 => "Marronnier",
-            24 => "Roquette",
-            25 => "Pigeon",
-            26 => "Lilas",
-            27 => "Anémone",
-            28 => "Pensée",
-            29 => "Myrtille",
-            30 => "Greffoir",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Floréal => match num {
-            1 => "Rose",
-            2 => "Chêne",
-            3 => "Fougère",
-            4 => "Aubépine",
-            5 => "Rossignol",
-            6 => "Ancolie",
-            7 => "Muguet",
-            8 => "Champignon",
-            9 => "Hyacinthe",
-            10 => "Râteau",
-            11 => "Rhubarbe",
-            12 => "Sainfoin",
-            13 => "Bâton-d’or",
-            14 => "Chamérisier",
-            15 => "Ver à soie",
-            16 => "Consoude",
-            17 => "Pimprenelle",
-            18 => "Corbeille d’or",
-            19 => "Arroche",
-            20 => "Sarcloir",
-            21 => "Statice",
-            22 => "Fritillaire",
-            23 => "Bourrache",
-            24 => "Valériane",
-            25 => "Carpe",
-            26 => "Fusain",
-            27 => "Civette",
-            28 => "Buglosse",
-            29 => "Sénevé",
-            30 => "Houlette",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Prairial => match num {
-            1 => "Luzerne",
-            2 => "Hémérocalle",
-            3 => "Trèfle",
-            4 => "Angélique",
-            5 => "Canard",
-            6 => "Mélisse",
-            7 => "Fromental",
-            8 => "Martagon",
-            9 => "Serpolet",
-            10 => "Faux",
-            11 => "Fraise",
-            12 => "Bétoine",
-            13 => "Pois",
-            14 => "Acacia",
-            15 => "Caille",
-            16 => "Œillet",
-            17 => "Sureau",
-            18 => "Pavot",
-            19 => "Tilleul",
-            20 => "Fourche",
-            21 => "Barbeau",
-            22 => "Camomille",
-            23 => "Chèvrefeuille",
-            24 => "Caille-lait",
-            25 => "Tanche",
-            26 => "Jasmin",
-            27 => "Verveine",
-            28 => "Thym",
-            29 => "Pivoine",
-            30 => "Chariot",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Messidor => match num {
-            1 => "Seigle",
-            2 => "Avoine",
-            3 => "Oignon",
-            4 => "Véronique",
-            5 => "Mulet",
-            6 => "Romarin",
-            7 => "Concombre",
-            8 => "Échalote",
-            9 => "Absinthe",
-            10 => "Faucille",
-            11 => "Coriandre",
-            12 => "Artichaut",
-            13 => "Giroflée",
-            14 => "Lavande",
-            15 => "Chamois",
-            16 => "Tabac",
-            17 => "Groseille",
-            18 => "Gesse",
-            19 => "Cerise",
-            20 => "Parc",
-            21 => "Menthe",
-            22 => "Cumin",
-            23 => "Haricot",
-            24 => "Orcanète",
-            25 => "Pintade",
-            26 => "Sauge",
-            27 => "Ail",
-            28 => "Vesce",
-            29 => "Blé",
-            30 => "Chalemie",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Thermidor => match num {
-            1 => "Épeautre",
-            2 => "Bouillon-blanc",
-            3 => "Melon",
-            4 => "Ivraie",
-            5 => "Bélier",
-            6 => "Prêle",
-            7 => "Armoise",
-            8 => "Carthame",
-            9 => "Mûre",
-            10 => "Arrosoir",
-            11 => "Panic",
-            12 => "Salicorne",
-            13 => "Abricot",
-            14 => "Basilic",
-            15 => "Brebis",
-            16 => "Guimauve",
-            17 => "Lin",
-            18 => "Amande",
-            19 => "Gentiane",
-            20 => "Écluse",
-            21 => "Carline",
-            22 => "Câprier",
-            23 => "Lentille",
-            24 => "Aunée",
-            25 => "Loutre",
-            26 => "Myrte",
-            27 => "Colza",
-            28 => "Lupin",
-            29 => "Coton",
-            30 => "Moulin",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Fructidor => match num {
-            1 => "Prune",
-            2 => "Millet",
-            3 => "Lycoperdon",
-            4 => "Escourgeon",
-            5 => "Saumon",
-            6 => "Tubéreuse",
-            7 => "Sucrion",
-            8 => "Apocyn",
-            9 => "Réglisse",
-            10 => "Échelle",
-            11 => "Pastèque",
-            12 => "Fenouil",
-            13 => "Épine-vinette",
-            14 => "Noix",
-            15 => "Truite",
-            16 => "Citron",
-            17 => "Cardère",
-            18 => "Nerprun",
-            19 => "Tagette",
-            20 => "Hotte",
-            21 => "Églantier",
-            22 => "Noisette",
-            23 => "Houblon",
-            24 => "Sorgho",
-            25 => "Écrevisse",
-            26 => "Bigarade",
-            27 => "Verge d’or",
-            28 => "Maïs",
-            29 => "Marron",
-            30 => "Panier",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Sansculotides => match num {
-            1 => "Vertu",
-            2 => "Génie",
-            3 => "Travail",
-            4 => "Opinion",
-            5 => "Récompenses",
-            6 => "Révolution",
-            _ => panic!("Invalid month number: {}", num),
-        },
-    }
+    Feast::new(month, num).name()
 }
 
+/// Returns the name of the feast of the day preceded by its article, such as `du Raisin`.
+///
+/// # Panics
+///
+/// Panics if the day is not in the month, see [`Feast::try_new`].
 pub fn day_name_with_article(month: Month, num: i64) -> &'static str {
-    match month {
-        Vendémiaire => match num {
-            1 => "du Raisin",
-            2 => "du Safran",
-            3 => "de la Châtaigne",
-            4 => "du Colchique",
-            5 => "du Cheval",
-            6 => "de la Balsamine",
-            7 => "de la Carotte",
-            8 => "de l’Amarante",
-            9 => "du Panais",
-            10 => "de la Cuve",
-            11 => "de la Pomme de terre",
-            12 => "de l’Immortelle",
-            13 => "du Potiron",
-            14 => "du Réséda",
-            15 => "de l’Âne",
-            16 => "de la Belle-de-nuit",
-            17 => "de la Citrouille",
-            18 => "du Sarrasin",
-            19 => "du Tournesol",
-            20 => "du Pressoir",
-            21 => "du Chanvre",
-            22 => "de la Pêche",
-            23 => "du Navet",
-            24 => "de l’Amaryllis",
-            25 => "du Bœuf",
-            26 => "de l’Aubergine",
-            27 => "du Piment",
-            28 => "de la Tomate",
-            29 => "de l’Orge",
-            30 => "du Tonneau",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Brumaire => match num {
-            1 => "de la Pomme",
-            2 => "du Céleri",
-            3 => "de la Poire",
-            4 => "de la Betterave",
-            5 => "de l’Oie",
-            6 => "de l’Héliotrope",
-            7 => "de la Figue",
-            8 => "de la Scorsonère",
-            9 => "de l’Alisier",
-            10 => "de la Charrue",
-            11 => "du Salsifis",
-            12 => "de la Mâcre",
-            13 => "du Topinambour",
-            14 => "de l’Endive",
-            15 => "du Dindon",
-            16 => "du Chervis",
-            17 => "du Cresson",
-            18 => "du Dentelaire",
-            19 => "de la Grenade",
-            20 => "de la Herse",
-            21 => "de la Bacchante",
-            22 => "de l’Azerole",
-            23 => "de la Garance",
-            24 => "de la Orange",
-            25 => "du Faisan",
-            26 => "de la Pistache",
-            27 => "de la Macjonc",
-            28 => "du Coing",
-            29 => "du Cormier",
-            30 => "du Rouleau",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Frimaire => match num {
-            1 => "de la Raiponce",
-            2 => "du Turneps",
-            3 => "de la Chicorée",
-            4 => "de la Nèfle",
-            5 => "du Cochon",
-            6 => "de la Mâche",
-            7 => "du Chou-fleur",
-            8 => "du Miel",
-            9 => "de la Genièvre",
-            10 => "de la Pioche",
-            11 => "de la Cire",
-            12 => "du Raifort",
-            13 => "du Cèdre",
-            14 => "du Sapin",
-            15 => "du Chevreuil",
-            16 => "de l’Ajonc",
-            17 => "du Cyprès",
-            18 => "du Lierre",
-            19 => "de la Sabine",
-            20 => "du Hoyau",
-            21 => "de l’Érable sucré",
-            22 => "de la Bruyère",
-            23 => "du Roseau",
-            24 => "de l’Oseille",
-            25 => "du Grillon",
-            26 => "du Pignon",
-            27 => "du Liège",
-            28 => "de la Truffe",
-            29 => "de l’Olive",
-            30 => "de la Pelle",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Nivôse => match num {
-            1 => "de la Tourbe",
-            2 => "de la Houille",
-            3 => "du Bitume",
-            4 => "du Soufre",
-            5 => "du Chien",
-            6 => "de la Lave",
-            7 => "de la Terre végétale",
-            8 => "du Fumier",
-            9 => "du Salpêtre",
-            10 => "du Fléau",
-            11 => "du Granit",
-            12 => "de l’Argile",
-            13 => "de l’Ardoise",
-            14 => "du Grès",
-            15 => "du Lapin",
-            16 => "du Silex",
-            17 => "du Marne",
-            18 => "de la Pierre à chaux",
-            19 => "du Marbre",
-            20 => "du Van",
-            21 => "de la Pierre à plâtre",
-            22 => "du Sel",
-            23 => "du Fer",
-            24 => "du Cuivre",
-            25 => "du Chat",
-            26 => "de l’Étain",
-            27 => "du Plomb",
-            28 => "du Zinc",
-            29 => "du Mercure",
-            30 => "du Crible",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Pluviôse => match num {
-            1 => "de la Lauréole",
-            2 => "de la Mousse",
-            3 => "du Fragon",
-            4 => "du Perce neige",
-            5 => "du Taureau",
-            6 => "du Laurier thym",
-            7 => "de l’Amadouvier",
-            8 => "du Mézéréon",
-            9 => "du Peuplier",
-            10 => "de la Cognée",
-            11 => "de l’Ellébore",
-            12 => "du Brocoli",
-            13 => "du Laurier",
-            14 => "de l’Avelinier",
-            15 => "de la Vache",
-            16 => "du Buis",
-            17 => "du Lichen",
-            18 => "de l’If",
-            19 => "de la Pulmonaire",
-            20 => "de la Serpette",
-            21 => "du Thlaspi",
-            22 => "de la Thymèle",
-            23 => "du Chiendent",
-            24 => "de la Trainasse",
-            25 => "du Lièvre",
-            26 => "de la Guède",
-            27 => "du Noisetier",
-            28 => "du Cyclamen",
-            29 => "de la Chélidoine",
-            30 => "du Traîneau",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Ventôse => match num {
-            1 => "du Tussilage",
-            2 => "du Cornouiller",
-            3 => "de la Violier",
-            4 => "du Troène",
-            5 => "du Bouc",
-            6 => "de l’Asaret",
-            7 => "de l’Alaterne",
-            8 => "de la Violette",
-            9 => "du Marceau",
-            10 => "de la Bêche",
-            11 => "du Narcisse",
-            12 => "de l’Orme",
-            13 => "de la Fumeterre",
-            14 => "du Vélar",
-            15 => "de la Chèvre",
-            16 => "de l’Épinard",
-            17 => "du Doronic",
-            18 => "du Mouron",
-            19 => "du Cerfeuil",
-            20 => "du Cordeau",
-            21 => "de la Mandragore",
-            22 => "du Persil",
-            23 => "du Cochléaria",
-            24 => "de la Pâquerette",
-            25 => "du Thon",
-            26 => "du Pissenlit",
-            27 => "de la Sylvie",
-            28 => "du Capillaire",
-            29 => "du Frêne",
-            30 => "du Plantoir",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Germinal => match num {
-            1 => "de la Primevère",
-            2 => "du Platane",
-            3 => "de l’Asperge",
-            4 => "de la Tulipe",
-            5 => "de la Poule",
-            6 => "de la Bette",
-            7 => "du Bouleau",
-            8 => "de la Jonquille",
-            9 => "de l’Aulne",
-            10 => "du Couvoir",
-            11 => "de la Pervenche",
-            12 => "du Charme",
-            13 => "de la Morille",
-            14 => "du Hêtre",
-            15 => "de l’Abeille",
-            16 => "de la Laitue",
-            17 => "du Mélèze",
-            18 => "de la Ciguë",
-            19 => "du Radis",
-            20 => "de la Ruche",
-            21 => "du Gainier",
-            22 => "de la Romaine",
-            23 => "du Marronnier",
-            24 => "de la Roquette",
-            25 => "du Pigeon",
-            26 => "du Lilas",
-            27 => "de l’Anémone",
-            28 => "de la Pensée",
-            29 => "de la Myrtille",
-            30 => "du Greffoir",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Floréal => match num {
-            1 => "de la Rose",
-            2 => "du Chêne",
-            3 => "de la Fougère",
-            4 => "de l’Aubépine",
-            5 => "du Rossignol",
-            6 => "de l’Ancolie",
-            7 => "du Muguet",
-            8 => "du Champignon",
-            9 => "de l’Hyacinthe",
-            10 => "du Râteau",
-            11 => "de la Rhubarbe",
-            12 => "du Sainfoin",
-            13 => "du Bâton-d’or",
-            14 => "du Chamérisier",
-            15 => "du Ver à soie",
-            16 => "de la Consoude",
-            17 => "de la Pimprenelle",
-            18 => "de la Corbeille d’or",
-            19 => "de l’Arroche",
-            20 => "du Sarcloir",
-            21 => "du Statice",
-            22 => "de la Fritillaire",
-            23 => "de la Bourrache",
-            24 => "de la Valériane",
-            25 => "de la Carpe",
-            26 => "du Fusain",
-            27 => "de la Civette",
-            28 => "de la Buglosse",
-            29 => "du Sénevé",
-            30 => "de la Houlette",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Prairial => match num {
-            1 => "de la Luzerne",
-            2 => "de l’Hémérocalle",
-            3 => "du Trèfle",
-            4 => "de l’Angélique",
-            5 => "du Canard",
-            6 => "de la Mélisse",
-            7 => "du Fromental",
-            8 => "du Martagon",
-            9 => "du Serpolet",
-            10 => "de la Faux",
-            11 => "de la Fraise",
-            12 => "de la Bétoine",
-            13 => "du Pois",
-            14 => "de l’Acacia",
-            15 => "de la Caille",
-            16 => "de l’Œillet",
-            17 => "du Sureau",
-            18 => "du Pavot",
-            19 => "du Tilleul",
-            20 => "de la Fourche",
-            21 => "du Barbeau",
-            22 => "de la Camomille",
-            23 => "du Chèvrefeuille",
-            24 => "du Caille-lait",
-            25 => "de la Tanche",
-            26 => "du Jasmin",
-            27 => "de la Verveine",
-            28 => "du Thym",
-            29 => "de la Pivoine",
-            30 => "du Chariot",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Messidor => match num {
-            1 => "du Seigle",
-            2 => "de l’Avoine",
-            3 => "de l’Oignon",
-            4 => "de la Véronique",
-            5 => "du Mulet",
-            6 => "du Romarin",
-            7 => "du Concombre",
-            8 => "de l’Échalote",
-            9 => "de l’Absinthe",
-            10 => "de la Faucille",
-            11 => "de la Coriandre",
-            12 => "de l’Artichaut",
-            13 => "de la Giroflée",
-            14 => "de la Lavande",
-            15 => "du Chamois",
-            16 => "du Tabac",
-            17 => "de la Groseille",
-            18 => "de la Gesse",
-            19 => "de la Cerise",
-            20 => "du Parc",
-            21 => "de la Menthe",
-            22 => "du Cumin",
-            23 => "du Haricot",
-            24 => "de l’Orcanète",
-            25 => "de la Pintade",
-            26 => "de la Sauge",
-            27 => "de l’Ail",
-            28 => "de la Vesce",
-            29 => "du Blé",
-            30 => "de la Chalemie",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Thermidor => match num {
-            1 => "de l’Épeautre",
-            2 => "du Bouillon-blanc",
-            3 => "du Melon",
-            4 => "de l’Ivraie",
-            5 => "du Bélier",
-            6 => "de la Prêle",
-            7 => "de l’Armoise",
-            8 => "du Carthame",
-            9 => "de la Mûre",
-            10 => "de l’Arrosoir",
-            11 => "du Panic",
-            12 => "de la Salicorne",
-            13 => "de l’Abricot",
-            14 => "du Basilic",
-            15 => "de la Brebis",
-            16 => "de la Guimauve",
-            17 => "du Lin",
-            18 => "de l’Amande",
-            19 => "de la Gentiane",
-            20 => "de l’Écluse",
-            21 => "de la Carline",
-            22 => "du Câprier",
-            23 => "de la Lentille",
-            24 => "de l’Aunée",
-            25 => "de la Loutre",
-            26 => "du Myrte",
-            27 => "du Colza",
-            28 => "du Lupin",
-            29 => "du Coton",
-            30 => "du Moulin",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Fructidor => match num {
-            1 => "de la Prune",
-            2 => "du Millet",
-            3 => "du Lycoperdon",
-            4 => "de l’Escourgeon",
-            5 => "du Saumon",
-            6 => "de la Tubéreuse",
-            7 => "du Sucrion",
-            8 => "de l’Apocyn",
-            9 => "de la Réglisse",
-            10 => "de l’Échelle",
-            11 => "de la Pastèque",
-            12 => "du Fenouil",
-            13 => "de l’Épine-vinette",
-            14 => "de la Noix",
-            15 => "de la Truite",
-            16 => "du Citron",
-            17 => "de la Cardère",
-            18 => "du Nerprun",
-            19 => "de la Tagette",
-            20 => "de la Hotte",
-            21 => "de l’Églantier",
-            22 => "de la Noisette",
-            23 => "du Houblon",
-            24 => "du Sorgho",
-            25 => "de l’Écrevisse",
-            26 => "de la Bigarade",
-            27 => "de la Verge d’or",
-            28 => "du Maïs",
-            29 => "du Marron",
-            30 => "du Panier",
-            _ => panic!("Invalid month number: {}", num),
-        },
-        Sansculotides => match num {
-            1 => "de la Vertu",
-            2 => "du Génie",
-            3 => "du Travail",
-            4 => "de l’Opinion",
-            5 => "des Récompenses",
-            6 => "de la Révolution",
-            _ => panic!("Invalid month number: {}", num),
-        },
-    }
+    Feast::new(month, num).name_with_article()
+}
+
+/// Returns the English name of the feast of the day, such as `Grape`.
+///
+/// # Panics
+///
+/// Panics if the day is not in the month, see [`Feast::try_new`].
+pub fn day_name_en(month: Month, num: i64) -> &'static str {
+    Feast::new(month, num).name_en()
 }
@@ -0,0 +1,195 @@
+use crate::*;
+
+/// A decade of a given month, such as the 2nd decade of Brumaire 233.
+///
+/// Each month has 3 decades of 10 days, and the Sansculotides form a single shorter decade of 5 or 6 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decade {
+    month: YearMonth,
+    decade0: i64,
+}
+
+impl Decade {
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - year is 0,
+    /// - decade is not in [1, 3], or is not 1 for the Sansculotides,
+    /// - the decade cannot be represented by a [`Timestamp`].
+    pub fn new(year: i64, month: Month, decade: i64) -> Self {
+        Self::try_new(year, month, decade).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Same as [`Decade::new`] but returns an error instead of panicking.
+    pub fn try_new(year: i64, month: Month, decade: i64) -> Result<Self, Error> {
+        let decades = match month {
+            Month::Sansculotides => 1,
+            _ => 3,
+        };
+        if !(1..=decades).contains(&decade) {
+            return Err(Error::DecadeOutOfRange);
+        }
+        Ok(Self {
+            month: YearMonth::try_new(year, month)?,
+            decade0: decade - 1,
+        })
+    }
+
+    pub fn year(&self) -> i64 {
+        self.month.year()
+    }
+
+    pub fn month(&self) -> Month {
+        self.month.month()
+    }
+
+    pub fn year_month(&self) -> YearMonth {
+        self.month
+    }
+
+    /// Returns the decade of the month but starting from 0.
+    pub fn decade0(&self) -> i64 {
+        self.decade0
+    }
+
+    /// Returns the decade of the month, from 1 to 3, always 1 for the Sansculotides.
+    pub fn decade(&self) -> i64 {
+        self.decade0 + 1
+    }
+
+    /// Returns whether this is the pseudo-decade of the Sansculotides.
+    pub fn is_sansculotides(&self) -> bool {
+        self.month() == Month::Sansculotides
+    }
+
+    pub fn first_day(&self) -> Date {
+        self.month.first_day() + Duration::from_decades(self.decade0)
+    }
+
+    pub fn last_day(&self) -> Date {
+        self.first_day() + Duration::from_days(self.num_days() - 1)
+    }
+
+    /// Returns the days of the decade, see [`Date::days_of_decade`].
+    pub fn days(&self) -> DateRange {
+        DateRange::new(self.first_day(), self.last_day())
+    }
+
+    /// Returns 10, or 5 or 6 for the Sansculotides.
+    pub fn num_days(&self) -> i64 {
+        match self.is_sansculotides() {
+            true => self.month.num_days(),
+            false => DAYS_PER_DECADE,
+        }
+    }
+
+    pub fn contains(&self, date: &Date) -> bool {
+        *self == Self::from(date)
+    }
+
+    /// Returns the following decade, the Sansculotides after the 3rd decade of Fructidor.
+    pub fn next(&self) -> Self {
+        match self.decade0 {
+            0 | 1 if !self.is_sansculotides() => Self {
+                decade0: self.decade0 + 1,
+                ..*self
+            },
+            _ => Self {
+                month: self.month.next(),
+                decade0: 0,
+            },
+        }
+    }
+
+    /// Returns the previous decade, the Sansculotides before the 1st decade of Vendémiaire.
+    pub fn prev(&self) -> Self {
+        match self.decade0 {
+            0 => {
+                let month = self.month.prev();
+                let decade0 = match month.month() {
+                    Month::Sansculotides => 0,
+                    _ => 2,
+                };
+                Self { month, decade0 }
+            }
+            _ => Self {
+                decade0: self.decade0 - 1,
+                ..*self
+            },
+        }
+    }
+}
+
+impl From<&Date> for Decade {
+    fn from(date: &Date) -> Self {
+        Self {
+            month: YearMonth::from(date),
+            decade0: date.decade0(),
+        }
+    }
+}
+
+impl From<Date> for Decade {
+    fn from(date: Date) -> Self {
+        Self::from(&date)
+    }
+}
+
+/// Formats as `2e décade de Brumaire 233`, or as `Sansculotides 233`.
+impl std::fmt::Display for Decade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.is_sansculotides(), self.decade()) {
+            (true, _) => write!(f, "{}", self.month),
+            (false, 1) => write!(f, "1re décade de {}", self.month),
+            (false, decade) => write!(f, "{decade}e décade de {}", self.month),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decade() {
+        let decade = Decade::from(Date::from_ymd(233, 2, 14));
+        assert_eq!(decade, Decade::new(233, Month::Brumaire, 2));
+        assert_eq!(decade.to_string(), "2e décade de Brumaire 233");
+        assert_eq!(decade.prev().to_string(), "1re décade de Brumaire 233");
+        assert_eq!(decade.first_day(), Date::from_ymd(233, 2, 11));
+        assert_eq!(decade.last_day(), Date::from_ymd(233, 2, 20));
+        assert!(decade.contains(&Date::from_ymd(233, 2, 20)));
+        assert!(!decade.contains(&Date::from_ymd(233, 2, 21)));
+        assert_eq!(
+            decade.days().collect::<Vec<_>>(),
+            Date::from_ymd(233, 2, 14)
+                .days_of_decade()
+                .collect::<Vec<_>>()
+        );
+
+        let sansculotides = Decade::from(Date::from_ymd(3, 13, 6));
+        assert!(sansculotides.is_sansculotides());
+        assert_eq!(sansculotides.to_string(), "Sansculotides 3");
+        assert_eq!(sansculotides.num_days(), 6);
+        assert_eq!(sansculotides.last_day(), Date::from_ymd(3, 13, 6));
+        assert_eq!(sansculotides.prev(), Decade::new(3, Month::Fructidor, 3));
+        assert_eq!(sansculotides.next(), Decade::new(4, Month::Vendémiaire, 1));
+        assert_eq!(sansculotides.next().prev(), sansculotides);
+        assert_eq!(Decade::new(4, Month::Sansculotides, 1).num_days(), 5);
+
+        let mut decade = Decade::new(1, Month::Vendémiaire, 1);
+        for date in Date::days_of_year(1).step_by(10) {
+            assert!(decade.contains(&date) && decade.first_day() == date);
+            decade = decade.next();
+        }
+        assert_eq!(decade, Decade::new(2, Month::Vendémiaire, 1));
+        assert_eq!(
+            Decade::try_new(1, Month::Sansculotides, 2),
+            Err(Error::DecadeOutOfRange)
+        );
+        assert_eq!(
+            Decade::try_new(1, Month::Brumaire, 0),
+            Err(Error::DecadeOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use crate::*;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed span of time, counted in decimal seconds (100000 per day) with nanosecond precision.
+///
+/// Durations can be added to or subtracted from a [`Timestamp`], a [`Date`] or a [`DateTime`],
+/// and subtracting two of them returns a duration.
+/// Adding a duration to a [`Date`] starts from midnight and drops the time of the result.
+///
+/// # Panics
+///
+/// The operators panic if the result cannot be represented by a [`Timestamp`], or if an operation between durations overflows.
+/// See the `checked_add`, `checked_sub`, `checked_mul` and `checked_neg` methods.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    nanos: i128,
+}
+
+impl Duration {
+    pub const ZERO: Self = Self { nanos: 0 };
+
+    pub const fn from_nanos(nanos: i128) -> Self {
+        Self { nanos }
+    }
+
+    pub const fn from_seconds(seconds: i64) -> Self {
+        Self::from_nanos(seconds as i128 * NANOS_PER_SECOND)
+    }
+
+    /// A decimal minute lasts 100 seconds.
+    pub const fn from_minutes(minutes: i64) -> Self {
+        Self::from_nanos(minutes as i128 * (SECONDS_PER_MINUTE as i128 * NANOS_PER_SECOND))
+    }
+
+    /// A decimal hour lasts 100 minutes.
+    pub const fn from_hours(hours: i64) -> Self {
+        Self::from_nanos(hours as i128 * (SECONDS_PER_HOUR as i128 * NANOS_PER_SECOND))
+    }
+
+    /// A day lasts 10 hours.
+    pub const fn from_days(days: i64) -> Self {
+        Self::from_nanos(days as i128 * (SECONDS_PER_DAY as i128 * NANOS_PER_SECOND))
+    }
+
+    /// A decade lasts 10 days.
+    pub const fn from_decades(decades: i64) -> Self {
+        Self::from_days(decades).mul_i128(DAYS_PER_DECADE as i128)
+    }
+
+    const fn mul_i128(self, rhs: i128) -> Self {
+        Self::from_nanos(self.nanos * rhs)
+    }
+
+    pub const fn as_nanos(&self) -> i128 {
+        self.nanos
+    }
+
+    /// Returns the number of whole seconds, rounded towards zero.
+    pub const fn num_seconds(&self) -> i128 {
+        self.nanos / NANOS_PER_SECOND
+    }
+
+    /// Returns the number of whole minutes, rounded towards zero.
+    pub const fn num_minutes(&self) -> i128 {
+        self.num_seconds() / SECONDS_PER_MINUTE as i128
+    }
+
+    /// Returns the number of whole hours, rounded towards zero.
+    pub const fn num_hours(&self) -> i128 {
+        self.num_seconds() / SECONDS_PER_HOUR as i128
+    }
+
+    /// Returns the number of whole days, rounded towards zero.
+    pub const fn num_days(&self) -> i128 {
+        self.num_seconds() / SECONDS_PER_DAY as i128
+    }
+
+    /// Returns the number of whole decades, rounded towards zero.
+    pub const fn num_decades(&self) -> i128 {
+        self.num_days() / DAYS_PER_DECADE as i128
+    }
+
+    /// Returns the nanoseconds beyond the whole seconds, negative if the duration is.
+    pub const fn subsec_nanos(&self) -> i64 {
+        (self.nanos % NANOS_PER_SECOND) as i64
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.nanos == 0
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the duration is the minimum value, whose absolute value cannot be represented.
+    pub const fn abs(&self) -> Self {
+        match self.checked_abs() {
+            Some(abs) => abs,
+            None => panic!("duration overflow"),
+        }
+    }
+
+    pub const fn checked_abs(&self) -> Option<Self> {
+        match self.nanos.checked_abs() {
+            Some(nanos) => Some(Self::from_nanos(nanos)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_add(&self, rhs: Self) -> Option<Self> {
+        match self.nanos.checked_add(rhs.nanos) {
+            Some(nanos) => Some(Self::from_nanos(nanos)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        match self.nanos.checked_sub(rhs.nanos) {
+            Some(nanos) => Some(Self::from_nanos(nanos)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_mul(&self, rhs: i64) -> Option<Self> {
+        match self.nanos.checked_mul(rhs as i128) {
+            Some(nanos) => Some(Self::from_nanos(nanos)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_neg(&self) -> Option<Self> {
+        match self.nanos.checked_neg() {
+            Some(nanos) => Some(Self::from_nanos(nanos)),
+            None => None,
+        }
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("duration overflow")
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("duration overflow")
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("duration overflow")
+    }
+}
+
+impl Mul<i64> for Duration {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        self.checked_mul(rhs).expect("duration overflow")
+    }
+}
+
+impl std::iter::Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl Timestamp {
+    /// Returns `None` if the result cannot be represented.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Self::checked_from_nanos(self.as_nanos().checked_add(duration.as_nanos())?)
+    }
+
+    /// Returns `None` if the result cannot be represented.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        Self::checked_from_nanos(self.as_nanos().checked_sub(duration.as_nanos())?)
+    }
+
+    /// Returns the duration elapsed since an earlier timestamp, negative if it is later.
+    pub fn duration_since(&self, earlier: Self) -> Duration {
+        Duration::from_nanos(self.as_nanos() - earlier.as_nanos())
+    }
+}
+
+impl Date {
+    /// Returns `None` if the result cannot be represented by a [`Timestamp`].
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Some(Self::from_timestamp(self.checked_timestamp()?.checked_add(duration)?))
+    }
+
+    /// Returns `None` if the result cannot be represented by a [`Timestamp`].
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        Some(Self::from_timestamp(self.checked_timestamp()?.checked_sub(duration)?))
+    }
+}
+
+impl DateTime {
+    /// Returns `None` if the result cannot be represented by a [`Timestamp`].
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        Some(Self::from_timestamp(self.checked_timestamp()?.checked_add(duration)?))
+    }
+
+    /// Returns `None` if the result cannot be represented by a [`Timestamp`].
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        Some(Self::from_timestamp(self.checked_timestamp()?.checked_sub(duration)?))
+    }
+}
+
+macro_rules! impl_duration_ops {
+    ($($type:ty),*) => {
+        $(
+            impl Add<Duration> for $type {
+                type Output = Self;
+
+                fn add(self, rhs: Duration) -> Self {
+                    self.checked_add(rhs).expect("timestamp out of range")
+                }
+            }
+
+            impl Sub<Duration> for $type {
+                type Output = Self;
+
+                fn sub(self, rhs: Duration) -> Self {
+                    self.checked_sub(rhs).expect("timestamp out of range")
+                }
+            }
+
+            impl AddAssign<Duration> for $type {
+                fn add_assign(&mut self, rhs: Duration) {
+                    *self = self.checked_add(rhs).expect("timestamp out of range");
+                }
+            }
+
+            impl SubAssign<Duration> for $type {
+                fn sub_assign(&mut self, rhs: Duration) {
+                    *self = self.checked_sub(rhs).expect("timestamp out of range");
+                }
+            }
+        )*
+    };
+}
+
+impl_duration_ops!(Timestamp, Date, DateTime);
+
+impl Sub for Timestamp {
+    type Output = Duration;
+
+    fn sub(self, rhs: Self) -> Duration {
+        self.duration_since(rhs)
+    }
+}
+
+impl Sub for Date {
+    type Output = Duration;
+
+    fn sub(self, rhs: Self) -> Duration {
+        self.timestamp().duration_since(rhs.timestamp())
+    }
+}
+
+impl Sub for DateTime {
+    type Output = Duration;
+
+    fn sub(self, rhs: Self) -> Duration {
+        self.timestamp().duration_since(rhs.timestamp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(Duration::from_decades(1), Duration::from_days(10));
+        assert_eq!(Duration::from_days(1), Duration::from_hours(10));
+        assert_eq!(Duration::from_hours(1), Duration::from_minutes(100));
+        assert_eq!(Duration::from_minutes(1), Duration::from_seconds(100));
+        assert_eq!(Duration::from_seconds(i64::MIN).num_seconds(), i64::MIN as i128);
+        assert_eq!(Duration::from_decades(i64::MAX).num_decades(), i64::MAX as i128);
+        let duration = -(Duration::from_days(3) + Duration::from_nanos(5));
+        assert_eq!(duration.num_days(), -3);
+        assert_eq!(duration.num_hours(), -30);
+        assert_eq!(duration.subsec_nanos(), -5);
+        assert_eq!(duration.abs(), Duration::from_days(3) + Duration::from_nanos(5));
+        assert_eq!(Duration::from_hours(2) * 5, Duration::from_days(1));
+        let max = Duration::from_nanos(i128::MAX);
+        assert_eq!(max.checked_add(Duration::from_nanos(1)), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Duration::from_nanos(i128::MIN).checked_neg(), None);
+        assert_eq!(max.checked_mul(-1).and_then(|d| d.checked_neg()), Some(max));
+        assert_eq!(
+            [Duration::from_hours(4), Duration::from_hours(6)].into_iter().sum::<Duration>(),
+            Duration::from_days(1)
+        );
+
+        let ts = Timestamp::from_seconds(0);
+        assert_eq!(ts + Duration::from_nanos(-1), Timestamp { seconds: -1, nanos: 999_999_999 });
+        assert_eq!(ts - Duration::from_minutes(1), Timestamp::from_seconds(-100));
+        assert_eq!(Timestamp::from_seconds(250) - ts, Duration::from_seconds(250));
+        assert_eq!(Timestamp::from_seconds(i64::MAX).checked_add(Duration::from_seconds(1)), None);
+
+        let mut date = Date::from_ymd(1, 12, 30);
+        date += Duration::from_days(1);
+        assert_eq!(date, Date::from_ymd(1, 13, 1));
+        assert_eq!(date.clone() + Duration::from_decades(1), Date::from_ymd(2, 1, 6));
+        assert_eq!(date.clone() - Duration::from_seconds(1), Date::from_ymd(1, 12, 30));
+        assert_eq!(Date::from_ymd(3, 1, 1) - Date::from_ymd(1, 1, 1), Duration::from_days(730));
+        assert_eq!(Date::from_ymd(5, 1, 1) - Date::from_ymd(3, 1, 1), Duration::from_days(731));
+
+        let mut datetime = DateTime::from_ymd_hms(233, 2, 11, 9, 99, 99);
+        datetime += Duration::from_seconds(1);
+        assert_eq!(datetime, DateTime::from_ymd(233, 2, 12));
+        datetime -= Duration::from_hours(5) + Duration::from_nanos(1);
+        assert_eq!(datetime.hour(), 4);
+        assert_eq!(datetime.nanosecond(), 999_999_999);
+        assert_eq!(
+            DateTime::from_ymd(233, 2, 12) - datetime,
+            Duration::from_hours(5) + Duration::from_nanos(1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "duration overflow")]
+    fn test_duration_overflow() {
+        let _ = Duration::from_nanos(i128::MAX) * 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "duration overflow")]
+    fn test_duration_abs_overflow() {
+        assert_eq!(Duration::from_nanos(-5).checked_abs(), Some(Duration::from_nanos(5)));
+        assert_eq!(Duration::from_nanos(i128::MIN).checked_abs(), None);
+        let _ = Duration::from_nanos(i128::MIN).abs();
+    }
+}
@@ -0,0 +1,66 @@
+/// Everything that can go wrong in this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// There is no year 0, the year before year 1 is year -1.
+    YearZero,
+    /// The month is not in [1, 13].
+    MonthOutOfRange,
+    /// The day of the month is not in [1, 30].
+    DayOutOfRange,
+    /// The month starting from 0 is not in [0, 12].
+    Month0OutOfRange,
+    /// The day of the month starting from 0 is not in [0, 29].
+    Day0OutOfRange,
+    /// The Sansculottide day is beyond the length of the year (5 days, or 6 in sextile years).
+    SansculottideDayOutOfRange,
+    /// The decade of the month is not in [1, 3], or is not 1 for the Sansculotides.
+    DecadeOutOfRange,
+    /// The day of the decade is not in [1, 10], or [1, 6] for Sansculottide days.
+    DecadeDayOutOfRange,
+    /// The hour is not in [0, 9].
+    HourOutOfRange,
+    /// The minute is not in [0, 99].
+    MinuteOutOfRange,
+    /// The second is not in [0, 99].
+    SecondOutOfRange,
+    /// The nanosecond is not in [0, 999999999].
+    NanosecondOutOfRange,
+    /// The date cannot be represented by a [`Timestamp`](crate::Timestamp).
+    TimestampOutOfRange,
+    /// The date cannot be represented by chrono.
+    ChronoOutOfRange,
+    /// The input doesn't match the expected format.
+    InvalidFormat,
+    /// The name of the day of the decade doesn't match the date.
+    DecadeDayMismatch,
+    /// Some fields of the input don't agree with each other, such as a day of the year and a month.
+    InconsistentFields,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Error::YearZero => "year cannot be 0",
+            Error::MonthOutOfRange => "month must be in [1, 13]",
+            Error::DayOutOfRange => "day must be in [1, 30]",
+            Error::Month0OutOfRange => "month0 must be in [0, 12]",
+            Error::Day0OutOfRange => "day0 must be in [0, 29]",
+            Error::SansculottideDayOutOfRange => "sansculottide day is beyond the end of the year",
+            Error::DecadeOutOfRange => "decade must be in [1, 3], or 1 for the Sansculotides",
+            Error::DecadeDayOutOfRange => "day of the decade is out of range",
+            Error::HourOutOfRange => "hour must be in [0, 9]",
+            Error::MinuteOutOfRange => "minute must be in [0, 99]",
+            Error::SecondOutOfRange => "second must be in [0, 99]",
+            Error::NanosecondOutOfRange => "nanosecond must be in [0, 999999999]",
+            Error::TimestampOutOfRange => "date cannot be represented by a timestamp",
+            Error::ChronoOutOfRange => "date cannot be represented by chrono",
+            Error::InvalidFormat => "input doesn't match the expected format",
+            Error::DecadeDayMismatch => "day of the decade doesn't match the date",
+            Error::InconsistentFields => "fields of the input are inconsistent",
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,702 @@
+use crate::text::{fold, levenshtein};
+use crate::{DAYS_PER_DECADE, DAYS_PER_MONTH, Month};
+use Gender::*;
+use Number::*;
+
+/// The article of a feast, in the traditional form `jour du Raisin`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Article {
+    /// `du`, before a masculine singular name.
+    Du,
+    /// `de la`, before a feminine singular name.
+    DeLa,
+    /// `de l’`, before a singular name starting with a vowel or a mute h.
+    DeL,
+    /// `des`, before a plural name.
+    Des,
+}
+
+impl Article {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Du => "du",
+            Self::DeLa => "de la",
+            Self::DeL => "de l’",
+            Self::Des => "des",
+        }
+    }
+}
+
+impl std::fmt::Display for Article {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Number {
+    Singular,
+    Plural,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FeastKind {
+    Plant,
+    /// Every quintidi.
+    Animal,
+    /// Every décadi.
+    Tool,
+    /// Every day of Nivôse that is not a quintidi or a décadi.
+    Mineral,
+    /// The Sansculottides.
+    Virtue,
+}
+
+/// The feast of a day: the plant, animal, tool, mineral or virtue it is named after.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Feast {
+    month: Month,
+    day: i64,
+    name: &'static str,
+    name_with_article: &'static str,
+    name_en: &'static str,
+    article: Article,
+    gender: Gender,
+    number: Number,
+    kind: FeastKind,
+}
+
+impl Feast {
+    /// # Panics
+    ///
+    /// Panics if the day is not in the month, see [`Feast::try_new`].
+    pub fn new(month: Month, day: i64) -> Self {
+        Self::try_new(month, day).unwrap_or_else(|| panic!("Invalid day number: {}", day))
+    }
+
+    /// Returns `None` if the day is not in [1, 30], or in [1, 6] for the Sansculottides.
+    pub fn try_new(month: Month, day: i64) -> Option<Self> {
+        let days = if month == Month::Sansculotides {
+            6
+        } else {
+            DAYS_PER_MONTH
+        };
+        if !(1..=days).contains(&day) {
+            return None;
+        }
+        let (article, name, name_with_article, name_en, gender, number) =
+            FEASTS[(month.num0() * DAYS_PER_MONTH + day - 1) as usize];
+        let kind = match (month, day % DAYS_PER_DECADE) {
+            (Month::Sansculotides, _) => FeastKind::Virtue,
+            (_, 5) => FeastKind::Animal,
+            (_, 0) => FeastKind::Tool,
+            (Month::Nivôse, _) => FeastKind::Mineral,
+            _ => FeastKind::Plant,
+        };
+        Some(Self {
+            month,
+            day,
+            name,
+            name_with_article,
+            name_en,
+            article,
+            gender,
+            number,
+            kind,
+        })
+    }
+
+    /// Returns the month of the feast.
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the day of the month of the feast, starting from 1.
+    pub fn day(&self) -> i64 {
+        self.day
+    }
+
+    /// Returns the bare name, such as `Raisin`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the name preceded by its article, such as `du Raisin`.
+    pub fn name_with_article(&self) -> &'static str {
+        self.name_with_article
+    }
+
+    /// Returns the English name, such as `Grape`.
+    pub fn name_en(&self) -> &'static str {
+        self.name_en
+    }
+
+    pub fn article(&self) -> Article {
+        self.article
+    }
+
+    pub fn gender(&self) -> Gender {
+        self.gender
+    }
+
+    pub fn number(&self) -> Number {
+        self.number
+    }
+
+    pub fn kind(&self) -> FeastKind {
+        self.kind
+    }
+
+    /// Returns the 366 feasts, in the order of the year.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..FEASTS.len() as i64)
+            .map(|i| Self::new(Month::from_num0(i / DAYS_PER_MONTH), i % DAYS_PER_MONTH + 1))
+    }
+
+    /// Returns the positions of the feasts with this name, in French or in English.
+    ///
+    /// Case, accents and a leading article (`du`, `de la`, `the`...) are ignored, so `la Pomme de terre` finds
+    /// Vendémiaire 11 and `grape` finds Vendémiaire 1.
+    pub fn lookup(name: &str) -> Vec<(Month, i64)> {
+        let name = fold(name);
+        let name = strip_article(&name);
+        Self::all()
+            .filter(|feast| feast.folded_names().any(|feast_name| feast_name == name))
+            .map(|feast| (feast.month, feast.day))
+            .collect()
+    }
+
+    /// Returns the feasts whose French or English name contains the query, or is close to it.
+    ///
+    /// Case, accents and a leading article are ignored, and a few typos are tolerated.
+    /// Exact matches come first, then names containing the query, then names close to it.
+    pub fn search(query: &str) -> Vec<Self> {
+        let query = fold(query);
+        let query = strip_article(&query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let max_distance = query.chars().count() / 4;
+        let mut matches = Self::all()
+            .filter_map(|feast| {
+                let score = feast
+                    .folded_names()
+                    .filter_map(|name| match name {
+                        _ if name == query => Some(0),
+                        _ if name.contains(query) => Some(1),
+                        _ => {
+                            let distance = levenshtein(&name, query);
+                            (distance <= max_distance).then_some(1 + distance)
+                        }
+                    })
+                    .min()?;
+                Some((score, feast))
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|(score, _)| *score);
+        matches.into_iter().map(|(_, feast)| feast).collect()
+    }
+
+    fn folded_names(&self) -> impl Iterator<Item = String> {
+        [self.name, self.name_en]
+            .into_iter()
+            .map(fold)
+    }
+}
+
+/// Removes a French or English article from a folded name.
+fn strip_article(name: &str) -> &str {
+    let name = name.trim();
+    let name = name.strip_prefix("jour ").unwrap_or(name).trim_start();
+    [
+        "du ", "de la ", "de l'", "des ", "le ", "la ", "l'", "les ", "the ",
+    ]
+    .into_iter()
+    .find_map(|article| name.strip_prefix(article))
+    .unwrap_or(name)
+    .trim_start()
+}
+
+/// Builds a row of [`FEASTS`], adding the name preceded by its article.
+macro_rules! feast {
+    (Du, $name:literal, $name_en:literal, $gender:ident, $number:ident) => {
+        (Article::Du, $name, concat!("du ", $name), $name_en, $gender, $number)
+    };
+    (DeLa, $name:literal, $name_en:literal, $gender:ident, $number:ident) => {
+        (Article::DeLa, $name, concat!("de la ", $name), $name_en, $gender, $number)
+    };
+    (DeL, $name:literal, $name_en:literal, $gender:ident, $number:ident) => {
+        (Article::DeL, $name, concat!("de l’", $name), $name_en, $gender, $number)
+    };
+    (Des, $name:literal, $name_en:literal, $gender:ident, $number:ident) => {
+        (Article::Des, $name, concat!("des ", $name), $name_en, $gender, $number)
+    };
+}
+
+/// Every feast with its article, bare name, name with article, English name, gender and number, in the order of the year.
+/// The Sansculottides only have 6 days, so they end the table.
+static FEASTS: [(Article, &str, &str, &str, Gender, Number); 366] = [
+    // Vendémiaire
+    feast!(Du, "Raisin", "Grape", Masculine, Singular),
+    feast!(Du, "Safran", "Saffron", Masculine, Singular),
+    feast!(DeLa, "Châtaigne", "Chestnut", Feminine, Singular),
+    feast!(Du, "Colchique", "Autumn crocus", Masculine, Singular),
+    feast!(Du, "Cheval", "Horse", Masculine, Singular),
+    feast!(DeLa, "Balsamine", "Impatiens", Feminine, Singular),
+    feast!(DeLa, "Carotte", "Carrot", Feminine, Singular),
+    feast!(DeL, "Amarante", "Amaranth", Feminine, Singular),
+    feast!(Du, "Panais", "Parsnip", Masculine, Singular),
+    feast!(DeLa, "Cuve", "Vat", Feminine, Singular),
+    feast!(DeLa, "Pomme de terre", "Potato", Feminine, Singular),
+    feast!(DeL, "Immortelle", "Everlasting flower", Feminine, Singular),
+    feast!(Du, "Potiron", "Squash", Masculine, Singular),
+    feast!(Du, "Réséda", "Mignonette", Masculine, Singular),
+    feast!(DeL, "Âne", "Donkey", Masculine, Singular),
+    feast!(DeLa, "Belle-de-nuit", "Four o’clock flower", Feminine, Singular),
+    feast!(DeLa, "Citrouille", "Pumpkin", Feminine, Singular),
+    feast!(Du, "Sarrasin", "Buckwheat", Masculine, Singular),
+    feast!(Du, "Tournesol", "Sunflower", Masculine, Singular),
+    feast!(Du, "Pressoir", "Wine press", Masculine, Singular),
+    feast!(Du, "Chanvre", "Hemp", Masculine, Singular),
+    feast!(DeLa, "Pêche", "Peach", Feminine, Singular),
+    feast!(Du, "Navet", "Turnip", Masculine, Singular),
+    feast!(DeL, "Amaryllis", "Amaryllis", Feminine, Singular),
+    feast!(Du, "Bœuf", "Ox", Masculine, Singular),
+    feast!(DeL, "Aubergine", "Eggplant", Feminine, Singular),
+    feast!(Du, "Piment", "Chili pepper", Masculine, Singular),
+    feast!(DeLa, "Tomate", "Tomato", Feminine, Singular),
+    feast!(DeL, "Orge", "Barley", Feminine, Singular),
+    feast!(Du, "Tonneau", "Barrel", Masculine, Singular),
+    // Brumaire
+    feast!(DeLa, "Pomme", "Apple", Feminine, Singular),
+    feast!(Du, "Céleri", "Celery", Masculine, Singular),
+    feast!(DeLa, "Poire", "Pear", Feminine, Singular),
+    feast!(DeLa, "Betterave", "Beetroot", Feminine, Singular),
+    feast!(DeL, "Oie", "Goose", Feminine, Singular),
+    feast!(DeL, "Héliotrope", "Heliotrope", Masculine, Singular),
+    feast!(DeLa, "Figue", "Fig", Feminine, Singular),
+    feast!(DeLa, "Scorsonère", "Black salsify", Feminine, Singular),
+    feast!(DeL, "Alisier", "Whitebeam", Masculine, Singular),
+    feast!(DeLa, "Charrue", "Plough", Feminine, Singular),
+    feast!(Du, "Salsifis", "Salsify", Masculine, Singular),
+    feast!(DeLa, "Mâcre", "Water chestnut", Feminine, Singular),
+    feast!(Du, "Topinambour", "Jerusalem artichoke", Masculine, Singular),
+    feast!(DeL, "Endive", "Endive", Feminine, Singular),
+    feast!(Du, "Dindon", "Turkey", Masculine, Singular),
+    feast!(Du, "Chervis", "Skirret", Masculine, Singular),
+    feast!(Du, "Cresson", "Watercress", Masculine, Singular),
+    feast!(Du, "Dentelaire", "Leadwort", Masculine, Singular),
+    feast!(DeLa, "Grenade", "Pomegranate", Feminine, Singular),
+    feast!(DeLa, "Herse", "Harrow", Feminine, Singular),
+    feast!(DeLa, "Bacchante", "Baccharis", Feminine, Singular),
+    feast!(DeL, "Azerole", "Azarole", Feminine, Singular),
+    feast!(DeLa, "Garance", "Madder", Feminine, Singular),
+    feast!(DeL, "Orange", "Orange", Feminine, Singular),
+    feast!(Du, "Faisan", "Pheasant", Masculine, Singular),
+    feast!(DeLa, "Pistache", "Pistachio", Feminine, Singular),
+    feast!(DeLa, "Macjonc", "Tuberous pea", Feminine, Singular),
+    feast!(Du, "Coing", "Quince", Masculine, Singular),
+    feast!(Du, "Cormier", "Service tree", Masculine, Singular),
+    feast!(Du, "Rouleau", "Roller", Masculine, Singular),
+    // Frimaire
+    feast!(DeLa, "Raiponce", "Rampion", Feminine, Singular),
+    feast!(Du, "Turneps", "Fodder turnip", Masculine, Singular),
+    feast!(DeLa, "Chicorée", "Chicory", Feminine, Singular),
+    feast!(DeLa, "Nèfle", "Medlar", Feminine, Singular),
+    feast!(Du, "Cochon", "Pig", Masculine, Singular),
+    feast!(DeLa, "Mâche", "Corn salad", Feminine, Singular),
+    feast!(Du, "Chou-fleur", "Cauliflower", Masculine, Singular),
+    feast!(Du, "Miel", "Honey", Masculine, Singular),
+    feast!(DeLa, "Genièvre", "Juniper", Feminine, Singular),
+    feast!(DeLa, "Pioche", "Pickaxe", Feminine, Singular),
+    feast!(DeLa, "Cire", "Wax", Feminine, Singular),
+    feast!(Du, "Raifort", "Horseradish", Masculine, Singular),
+    feast!(Du, "Cèdre", "Cedar", Masculine, Singular),
+    feast!(Du, "Sapin", "Fir", Masculine, Singular),
+    feast!(Du, "Chevreuil", "Roe deer", Masculine, Singular),
+    feast!(DeL, "Ajonc", "Gorse", Masculine, Singular),
+    feast!(Du, "Cyprès", "Cypress", Masculine, Singular),
+    feast!(Du, "Lierre", "Ivy", Masculine, Singular),
+    feast!(DeLa, "Sabine", "Savin juniper", Feminine, Singular),
+    feast!(Du, "Hoyau", "Grub hoe", Masculine, Singular),
+    feast!(DeL, "Érable sucré", "Sugar maple", Masculine, Singular),
+    feast!(DeLa, "Bruyère", "Heather", Feminine, Singular),
+    feast!(Du, "Roseau", "Reed", Masculine, Singular),
+    feast!(DeL, "Oseille", "Sorrel", Feminine, Singular),
+    feast!(Du, "Grillon", "Cricket", Masculine, Singular),
+    feast!(Du, "Pignon", "Pine nut", Masculine, Singular),
+    feast!(Du, "Liège", "Cork", Masculine, Singular),
+    feast!(DeLa, "Truffe", "Truffle", Feminine, Singular),
+    feast!(DeL, "Olive", "Olive", Feminine, Singular),
+    feast!(DeLa, "Pelle", "Shovel", Feminine, Singular),
+    // Nivôse
+    feast!(DeLa, "Tourbe", "Peat", Feminine, Singular),
+    feast!(DeLa, "Houille", "Coal", Feminine, Singular),
+    feast!(Du, "Bitume", "Bitumen", Masculine, Singular),
+    feast!(Du, "Soufre", "Sulphur", Masculine, Singular),
+    feast!(Du, "Chien", "Dog", Masculine, Singular),
+    feast!(DeLa, "Lave", "Lava", Feminine, Singular),
+    feast!(DeLa, "Terre végétale", "Topsoil", Feminine, Singular),
+    feast!(Du, "Fumier", "Manure", Masculine, Singular),
+    feast!(Du, "Salpêtre", "Saltpetre", Masculine, Singular),
+    feast!(Du, "Fléau", "Flail", Masculine, Singular),
+    feast!(Du, "Granit", "Granite", Masculine, Singular),
+    feast!(DeL, "Argile", "Clay", Feminine, Singular),
+    feast!(DeL, "Ardoise", "Slate", Feminine, Singular),
+    feast!(Du, "Grès", "Sandstone", Masculine, Singular),
+    feast!(Du, "Lapin", "Rabbit", Masculine, Singular),
+    feast!(Du, "Silex", "Flint", Masculine, Singular),
+    feast!(Du, "Marne", "Marl", Masculine, Singular),
+    feast!(DeLa, "Pierre à chaux", "Limestone", Feminine, Singular),
+    feast!(Du, "Marbre", "Marble", Masculine, Singular),
+    feast!(Du, "Van", "Winnowing basket", Masculine, Singular),
+    feast!(DeLa, "Pierre à plâtre", "Gypsum", Feminine, Singular),
+    feast!(Du, "Sel", "Salt", Masculine, Singular),
+    feast!(Du, "Fer", "Iron", Masculine, Singular),
+    feast!(Du, "Cuivre", "Copper", Masculine, Singular),
+    feast!(Du, "Chat", "Cat", Masculine, Singular),
+    feast!(DeL, "Étain", "Tin", Masculine, Singular),
+    feast!(Du, "Plomb", "Lead", Masculine, Singular),
+    feast!(Du, "Zinc", "Zinc", Masculine, Singular),
+    feast!(Du, "Mercure", "Mercury", Masculine, Singular),
+    feast!(Du, "Crible", "Sieve", Masculine, Singular),
+    // Pluviôse
+    feast!(DeLa, "Lauréole", "Spurge laurel", Feminine, Singular),
+    feast!(DeLa, "Mousse", "Moss", Feminine, Singular),
+    feast!(Du, "Fragon", "Butcher’s broom", Masculine, Singular),
+    feast!(Du, "Perce neige", "Snowdrop", Masculine, Singular),
+    feast!(Du, "Taureau", "Bull", Masculine, Singular),
+    feast!(Du, "Laurier thym", "Laurustinus", Masculine, Singular),
+    feast!(DeL, "Amadouvier", "Tinder fungus", Masculine, Singular),
+    feast!(Du, "Mézéréon", "Mezereon", Masculine, Singular),
+    feast!(Du, "Peuplier", "Poplar", Masculine, Singular),
+    feast!(DeLa, "Cognée", "Axe", Feminine, Singular),
+    feast!(DeL, "Ellébore", "Hellebore", Masculine, Singular),
+    feast!(Du, "Brocoli", "Broccoli", Masculine, Singular),
+    feast!(Du, "Laurier", "Laurel", Masculine, Singular),
+    feast!(DeL, "Avelinier", "Filbert", Masculine, Singular),
+    feast!(DeLa, "Vache", "Cow", Feminine, Singular),
+    feast!(Du, "Buis", "Box tree", Masculine, Singular),
+    feast!(Du, "Lichen", "Lichen", Masculine, Singular),
+    feast!(DeL, "If", "Yew", Masculine, Singular),
+    feast!(DeLa, "Pulmonaire", "Lungwort", Feminine, Singular),
+    feast!(DeLa, "Serpette", "Billhook", Feminine, Singular),
+    feast!(Du, "Thlaspi", "Pennycress", Masculine, Singular),
+    feast!(DeLa, "Thymèle", "Daphne", Feminine, Singular),
+    feast!(Du, "Chiendent", "Couch grass", Masculine, Singular),
+    feast!(DeLa, "Trainasse", "Knotgrass", Feminine, Singular),
+    feast!(Du, "Lièvre", "Hare", Masculine, Singular),
+    feast!(DeLa, "Guède", "Woad", Feminine, Singular),
+    feast!(Du, "Noisetier", "Hazel", Masculine, Singular),
+    feast!(Du, "Cyclamen", "Cyclamen", Masculine, Singular),
+    feast!(DeLa, "Chélidoine", "Celandine", Feminine, Singular),
+    feast!(Du, "Traîneau", "Sledge", Masculine, Singular),
+    // Ventôse
+    feast!(Du, "Tussilage", "Coltsfoot", Masculine, Singular),
+    feast!(Du, "Cornouiller", "Dogwood", Masculine, Singular),
+    feast!(DeLa, "Violier", "Stock", Feminine, Singular),
+    feast!(Du, "Troène", "Privet", Masculine, Singular),
+    feast!(Du, "Bouc", "Billy goat", Masculine, Singular),
+    feast!(DeL, "Asaret", "Wild ginger", Masculine, Singular),
+    feast!(DeL, "Alaterne", "Italian buckthorn", Masculine, Singular),
+    feast!(DeLa, "Violette", "Violet", Feminine, Singular),
+    feast!(Du, "Marceau", "Goat willow", Masculine, Singular),
+    feast!(DeLa, "Bêche", "Spade", Feminine, Singular),
+    feast!(Du, "Narcisse", "Narcissus", Masculine, Singular),
+    feast!(DeL, "Orme", "Elm", Masculine, Singular),
+    feast!(DeLa, "Fumeterre", "Fumitory", Feminine, Singular),
+    feast!(Du, "Vélar", "Hedge mustard", Masculine, Singular),
+    feast!(DeLa, "Chèvre", "Goat", Feminine, Singular),
+    feast!(DeL, "Épinard", "Spinach", Masculine, Singular),
+    feast!(Du, "Doronic", "Leopard’s bane", Masculine, Singular),
+    feast!(Du, "Mouron", "Pimpernel", Masculine, Singular),
+    feast!(Du, "Cerfeuil", "Chervil", Masculine, Singular),
+    feast!(Du, "Cordeau", "Garden line", Masculine, Singular),
+    feast!(DeLa, "Mandragore", "Mandrake", Feminine, Singular),
+    feast!(Du, "Persil", "Parsley", Masculine, Singular),
+    feast!(Du, "Cochléaria", "Scurvy grass", Masculine, Singular),
+    feast!(DeLa, "Pâquerette", "Daisy", Feminine, Singular),
+    feast!(Du, "Thon", "Tuna", Masculine, Singular),
+    feast!(Du, "Pissenlit", "Dandelion", Masculine, Singular),
+    feast!(DeLa, "Sylvie", "Wood anemone", Feminine, Singular),
+    feast!(Du, "Capillaire", "Maidenhair fern", Masculine, Singular),
+    feast!(Du, "Frêne", "Ash", Masculine, Singular),
+    feast!(Du, "Plantoir", "Dibber", Masculine, Singular),
+    // Germinal
+    feast!(DeLa, "Primevère", "Primrose", Feminine, Singular),
+    feast!(Du, "Platane", "Plane tree", Masculine, Singular),
+    feast!(DeL, "Asperge", "Asparagus", Feminine, Singular),
+    feast!(DeLa, "Tulipe", "Tulip", Feminine, Singular),
+    feast!(DeLa, "Poule", "Hen", Feminine, Singular),
+    feast!(DeLa, "Bette", "Chard", Feminine, Singular),
+    feast!(Du, "Bouleau", "Birch", Masculine, Singular),
+    feast!(DeLa, "Jonquille", "Daffodil", Feminine, Singular),
+    feast!(DeL, "Aulne", "Alder", Masculine, Singular),
+    feast!(Du, "Couvoir", "Hatchery", Masculine, Singular),
+    feast!(DeLa, "Pervenche", "Periwinkle", Feminine, Singular),
+    feast!(Du, "Charme", "Hornbeam", Masculine, Singular),
+    feast!(DeLa, "Morille", "Morel", Feminine, Singular),
+    feast!(Du, "Hêtre", "Beech", Masculine, Singular),
+    feast!(DeL, "Abeille", "Bee", Feminine, Singular),
+    feast!(DeLa, "Laitue", "Lettuce", Feminine, Singular),
+    feast!(Du, "Mélèze", "Larch", Masculine, Singular),
+    feast!(DeLa, "Ciguë", "Hemlock", Feminine, Singular),
+    feast!(Du, "Radis", "Radish", Masculine, Singular),
+    feast!(DeLa, "Ruche", "Hive", Feminine, Singular),
+    feast!(Du, "Gainier", "Judas tree", Masculine, Singular),
+    feast!(DeLa, "Romaine", "Romaine lettuce", Feminine, Singular),
+    feast!(Du, "Marronnier", "Horse chestnut", Masculine, Singular),
+    feast!(DeLa, "Roquette", "Rocket", Feminine, Singular),
+    feast!(Du, "Pigeon", "Pigeon", Masculine, Singular),
+    feast!(Du, "Lilas", "Lilac", Masculine, Singular),
+    feast!(DeL, "Anémone", "Anemone", Feminine, Singular),
+    feast!(DeLa, "Pensée", "Pansy", Feminine, Singular),
+    feast!(DeLa, "Myrtille", "Bilberry", Feminine, Singular),
+    feast!(Du, "Greffoir", "Grafting knife", Masculine, Singular),
+    // Floréal
+    feast!(DeLa, "Rose", "Rose", Feminine, Singular),
+    feast!(Du, "Chêne", "Oak", Masculine, Singular),
+    feast!(DeLa, "Fougère", "Fern", Feminine, Singular),
+    feast!(DeL, "Aubépine", "Hawthorn", Feminine, Singular),
+    feast!(Du, "Rossignol", "Nightingale", Masculine, Singular),
+    feast!(DeL, "Ancolie", "Columbine", Feminine, Singular),
+    feast!(Du, "Muguet", "Lily of the valley", Masculine, Singular),
+    feast!(Du, "Champignon", "Mushroom", Masculine, Singular),
+    feast!(DeL, "Hyacinthe", "Hyacinth", Feminine, Singular),
+    feast!(Du, "Râteau", "Rake", Masculine, Singular),
+    feast!(DeLa, "Rhubarbe", "Rhubarb", Feminine, Singular),
+    feast!(Du, "Sainfoin", "Sainfoin", Masculine, Singular),
+    feast!(Du, "Bâton-d’or", "Wallflower", Masculine, Singular),
+    feast!(Du, "Chamérisier", "Fly honeysuckle", Masculine, Singular),
+    feast!(Du, "Ver à soie", "Silkworm", Masculine, Singular),
+    feast!(DeLa, "Consoude", "Comfrey", Feminine, Singular),
+    feast!(DeLa, "Pimprenelle", "Salad burnet", Feminine, Singular),
+    feast!(DeLa, "Corbeille d’or", "Basket of gold", Feminine, Singular),
+    feast!(DeL, "Arroche", "Orache", Feminine, Singular),
+    feast!(Du, "Sarcloir", "Weeding hoe", Masculine, Singular),
+    feast!(Du, "Statice", "Sea lavender", Masculine, Singular),
+    feast!(DeLa, "Fritillaire", "Fritillary", Feminine, Singular),
+    feast!(DeLa, "Bourrache", "Borage", Feminine, Singular),
+    feast!(DeLa, "Valériane", "Valerian", Feminine, Singular),
+    feast!(DeLa, "Carpe", "Carp", Feminine, Singular),
+    feast!(Du, "Fusain", "Spindle tree", Masculine, Singular),
+    feast!(DeLa, "Civette", "Chive", Feminine, Singular),
+    feast!(DeLa, "Buglosse", "Bugloss", Feminine, Singular),
+    feast!(Du, "Sénevé", "Wild mustard", Masculine, Singular),
+    feast!(DeLa, "Houlette", "Shepherd’s crook", Feminine, Singular),
+    // Prairial
+    feast!(DeLa, "Luzerne", "Alfalfa", Feminine, Singular),
+    feast!(DeL, "Hémérocalle", "Daylily", Feminine, Singular),
+    feast!(Du, "Trèfle", "Clover", Masculine, Singular),
+    feast!(DeL, "Angélique", "Angelica", Feminine, Singular),
+    feast!(Du, "Canard", "Duck", Masculine, Singular),
+    feast!(DeLa, "Mélisse", "Lemon balm", Feminine, Singular),
+    feast!(Du, "Fromental", "Oat grass", Masculine, Singular),
+    feast!(Du, "Martagon", "Martagon lily", Masculine, Singular),
+    feast!(Du, "Serpolet", "Wild thyme", Masculine, Singular),
+    feast!(DeLa, "Faux", "Scythe", Feminine, Singular),
+    feast!(DeLa, "Fraise", "Strawberry", Feminine, Singular),
+    feast!(DeLa, "Bétoine", "Betony", Feminine, Singular),
+    feast!(Du, "Pois", "Pea", Masculine, Singular),
+    feast!(DeL, "Acacia", "Acacia", Masculine, Singular),
+    feast!(DeLa, "Caille", "Quail", Feminine, Singular),
+    feast!(DeL, "Œillet", "Carnation", Masculine, Singular),
+    feast!(Du, "Sureau", "Elder", Masculine, Singular),
+    feast!(Du, "Pavot", "Poppy", Masculine, Singular),
+    feast!(Du, "Tilleul", "Linden", Masculine, Singular),
+    feast!(DeLa, "Fourche", "Pitchfork", Feminine, Singular),
+    feast!(Du, "Barbeau", "Cornflower", Masculine, Singular),
+    feast!(DeLa, "Camomille", "Chamomile", Feminine, Singular),
+    feast!(Du, "Chèvrefeuille", "Honeysuckle", Masculine, Singular),
+    feast!(Du, "Caille-lait", "Bedstraw", Masculine, Singular),
+    feast!(DeLa, "Tanche", "Tench", Feminine, Singular),
+    feast!(Du, "Jasmin", "Jasmine", Masculine, Singular),
+    feast!(DeLa, "Verveine", "Verbena", Feminine, Singular),
+    feast!(Du, "Thym", "Thyme", Masculine, Singular),
+    feast!(DeLa, "Pivoine", "Peony", Feminine, Singular),
+    feast!(Du, "Chariot", "Cart", Masculine, Singular),
+    // Messidor
+    feast!(Du, "Seigle", "Rye", Masculine, Singular),
+    feast!(DeL, "Avoine", "Oats", Feminine, Singular),
+    feast!(DeL, "Oignon", "Onion", Masculine, Singular),
+    feast!(DeLa, "Véronique", "Speedwell", Feminine, Singular),
+    feast!(Du, "Mulet", "Mule", Masculine, Singular),
+    feast!(Du, "Romarin", "Rosemary", Masculine, Singular),
+    feast!(Du, "Concombre", "Cucumber", Masculine, Singular),
+    feast!(DeL, "Échalote", "Shallot", Feminine, Singular),
+    feast!(DeL, "Absinthe", "Wormwood", Feminine, Singular),
+    feast!(DeLa, "Faucille", "Sickle", Feminine, Singular),
+    feast!(DeLa, "Coriandre", "Coriander", Feminine, Singular),
+    feast!(DeL, "Artichaut", "Artichoke", Masculine, Singular),
+    feast!(DeLa, "Giroflée", "Gillyflower", Feminine, Singular),
+    feast!(DeLa, "Lavande", "Lavender", Feminine, Singular),
+    feast!(Du, "Chamois", "Chamois", Masculine, Singular),
+    feast!(Du, "Tabac", "Tobacco", Masculine, Singular),
+    feast!(DeLa, "Groseille", "Redcurrant", Feminine, Singular),
+    feast!(DeLa, "Gesse", "Grass pea", Feminine, Singular),
+    feast!(DeLa, "Cerise", "Cherry", Feminine, Singular),
+    feast!(Du, "Parc", "Sheepfold", Masculine, Singular),
+    feast!(DeLa, "Menthe", "Mint", Feminine, Singular),
+    feast!(Du, "Cumin", "Cumin", Masculine, Singular),
+    feast!(Du, "Haricot", "Bean", Masculine, Singular),
+    feast!(DeL, "Orcanète", "Alkanet", Feminine, Singular),
+    feast!(DeLa, "Pintade", "Guinea fowl", Feminine, Singular),
+    feast!(DeLa, "Sauge", "Sage", Feminine, Singular),
+    feast!(DeL, "Ail", "Garlic", Masculine, Singular),
+    feast!(DeLa, "Vesce", "Vetch", Feminine, Singular),
+    feast!(Du, "Blé", "Wheat", Masculine, Singular),
+    feast!(DeLa, "Chalemie", "Shawm", Feminine, Singular),
+    // Thermidor
+    feast!(DeL, "Épeautre", "Spelt", Masculine, Singular),
+    feast!(Du, "Bouillon-blanc", "Mullein", Masculine, Singular),
+    feast!(Du, "Melon", "Melon", Masculine, Singular),
+    feast!(DeL, "Ivraie", "Darnel", Feminine, Singular),
+    feast!(Du, "Bélier", "Ram", Masculine, Singular),
+    feast!(DeLa, "Prêle", "Horsetail", Feminine, Singular),
+    feast!(DeL, "Armoise", "Mugwort", Feminine, Singular),
+    feast!(Du, "Carthame", "Safflower", Masculine, Singular),
+    feast!(DeLa, "Mûre", "Blackberry", Feminine, Singular),
+    feast!(DeL, "Arrosoir", "Watering can", Masculine, Singular),
+    feast!(Du, "Panic", "Panic grass", Masculine, Singular),
+    feast!(DeLa, "Salicorne", "Samphire", Feminine, Singular),
+    feast!(DeL, "Abricot", "Apricot", Masculine, Singular),
+    feast!(Du, "Basilic", "Basil", Masculine, Singular),
+    feast!(DeLa, "Brebis", "Ewe", Feminine, Singular),
+    feast!(DeLa, "Guimauve", "Marshmallow", Feminine, Singular),
+    feast!(Du, "Lin", "Flax", Masculine, Singular),
+    feast!(DeL, "Amande", "Almond", Feminine, Singular),
+    feast!(DeLa, "Gentiane", "Gentian", Feminine, Singular),
+    feast!(DeL, "Écluse", "Lock", Feminine, Singular),
+    feast!(DeLa, "Carline", "Carline thistle", Feminine, Singular),
+    feast!(Du, "Câprier", "Caper", Masculine, Singular),
+    feast!(DeLa, "Lentille", "Lentil", Feminine, Singular),
+    feast!(DeL, "Aunée", "Elecampane", Feminine, Singular),
+    feast!(DeLa, "Loutre", "Otter", Feminine, Singular),
+    feast!(Du, "Myrte", "Myrtle", Masculine, Singular),
+    feast!(Du, "Colza", "Rapeseed", Masculine, Singular),
+    feast!(Du, "Lupin", "Lupin", Masculine, Singular),
+    feast!(Du, "Coton", "Cotton", Masculine, Singular),
+    feast!(Du, "Moulin", "Mill", Masculine, Singular),
+    // Fructidor
+    feast!(DeLa, "Prune", "Plum", Feminine, Singular),
+    feast!(Du, "Millet", "Millet", Masculine, Singular),
+    feast!(Du, "Lycoperdon", "Puffball", Masculine, Singular),
+    feast!(DeL, "Escourgeon", "Six-row barley", Masculine, Singular),
+    feast!(Du, "Saumon", "Salmon", Masculine, Singular),
+    feast!(DeLa, "Tubéreuse", "Tuberose", Feminine, Singular),
+    feast!(Du, "Sucrion", "Sugar melon", Masculine, Singular),
+    feast!(DeL, "Apocyn", "Dogbane", Masculine, Singular),
+    feast!(DeLa, "Réglisse", "Liquorice", Feminine, Singular),
+    feast!(DeL, "Échelle", "Ladder", Feminine, Singular),
+    feast!(DeLa, "Pastèque", "Watermelon", Feminine, Singular),
+    feast!(Du, "Fenouil", "Fennel", Masculine, Singular),
+    feast!(DeL, "Épine-vinette", "Barberry", Feminine, Singular),
+    feast!(DeLa, "Noix", "Walnut", Feminine, Singular),
+    feast!(DeLa, "Truite", "Trout", Feminine, Singular),
+    feast!(Du, "Citron", "Lemon", Masculine, Singular),
+    feast!(DeLa, "Cardère", "Teasel", Feminine, Singular),
+    feast!(Du, "Nerprun", "Buckthorn", Masculine, Singular),
+    feast!(DeLa, "Tagette", "Marigold", Feminine, Singular),
+    feast!(DeLa, "Hotte", "Harvesting basket", Feminine, Singular),
+    feast!(DeL, "Églantier", "Wild rose", Masculine, Singular),
+    feast!(DeLa, "Noisette", "Hazelnut", Feminine, Singular),
+    feast!(Du, "Houblon", "Hops", Masculine, Singular),
+    feast!(Du, "Sorgho", "Sorghum", Masculine, Singular),
+    feast!(DeL, "Écrevisse", "Crayfish", Feminine, Singular),
+    feast!(DeLa, "Bigarade", "Bitter orange", Feminine, Singular),
+    feast!(DeLa, "Verge d’or", "Goldenrod", Feminine, Singular),
+    feast!(Du, "Maïs", "Maize", Masculine, Singular),
+    feast!(Du, "Marron", "Sweet chestnut", Masculine, Singular),
+    feast!(Du, "Panier", "Basket", Masculine, Singular),
+    // Sansculotides
+    feast!(DeLa, "Vertu", "Virtue", Feminine, Singular),
+    feast!(Du, "Génie", "Genius", Masculine, Singular),
+    feast!(Du, "Travail", "Labour", Masculine, Singular),
+    feast!(DeL, "Opinion", "Opinion", Feminine, Singular),
+    feast!(Des, "Récompenses", "Rewards", Feminine, Plural),
+    feast!(DeLa, "Révolution", "Revolution", Feminine, Singular),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+
+    #[test]
+    fn test_feast() {
+        let feast = Feast::new(Month::Vendémiaire, 1);
+        assert_eq!(feast.name(), "Raisin");
+        assert_eq!(feast.name_with_article(), "du Raisin");
+        assert_eq!(feast.name_en(), "Grape");
+        assert_eq!(feast.article(), Article::Du);
+        assert_eq!((feast.gender(), feast.number()), (Masculine, Singular));
+        assert_eq!(feast.kind(), FeastKind::Plant);
+
+        let feast = Feast::new(Month::Vendémiaire, 8);
+        assert_eq!((feast.name(), feast.article()), ("Amarante", Article::DeL));
+        assert_eq!(feast.gender(), Feminine);
+        let feast = Feast::new(Month::Brumaire, 24);
+        assert_eq!((feast.name(), feast.article()), ("Orange", Article::DeL));
+        assert_eq!(feast.name_with_article(), "de l’Orange");
+        let feast = Feast::new(Month::Sansculotides, 5);
+        assert_eq!(
+            (feast.name(), feast.article()),
+            ("Récompenses", Article::Des)
+        );
+        assert_eq!((feast.number(), feast.kind()), (Plural, FeastKind::Virtue));
+
+        assert_eq!(Feast::new(Month::Vendémiaire, 5).kind(), FeastKind::Animal);
+        assert_eq!(Feast::new(Month::Nivôse, 10).kind(), FeastKind::Tool);
+        assert_eq!(Feast::new(Month::Nivôse, 11).kind(), FeastKind::Mineral);
+        assert_eq!(Feast::try_new(Month::Sansculotides, 7), None);
+        assert_eq!(Feast::try_new(Month::Brumaire, 0), None);
+        assert_eq!(Date::from_ymd(233, 2, 11).feast().name(), "Salsifis");
+
+        for month in (1..=13).map(Month::from_num) {
+            for day in (1..=30).filter_map(|day| Feast::try_new(month, day)) {
+                let article = day.article().as_str();
+                assert!(day.name_with_article().starts_with(article));
+                assert!(day.name_with_article().ends_with(day.name()));
+                assert_eq!(day.article() == Article::Des, day.number() == Plural);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(Feast::all().count(), 366);
+        assert_eq!(Feast::lookup("Pomme de terre"), [(Month::Vendémiaire, 11)]);
+        assert_eq!(
+            Feast::lookup("de la pomme de terre"),
+            [(Month::Vendémiaire, 11)]
+        );
+        assert_eq!(Feast::lookup("CHEVAL"), [(Month::Vendémiaire, 5)]);
+        assert_eq!(Feast::lookup("the grape"), [(Month::Vendémiaire, 1)]);
+        assert_eq!(Feast::lookup("jour de l’Ane"), [(Month::Vendémiaire, 15)]);
+        assert_eq!(Feast::lookup("Erable sucre"), [(Month::Frimaire, 21)]);
+        assert!(Feast::lookup("Pomme de").is_empty());
+
+        let positions = |feasts: Vec<Feast>| {
+            feasts
+                .iter()
+                .map(|feast| (feast.month(), feast.day()))
+                .collect::<Vec<_>>()
+        };
+        let results = positions(Feast::search("pomme"));
+        assert_eq!(results[0], (Month::Brumaire, 1));
+        assert!(results.contains(&(Month::Vendémiaire, 11)));
+        assert_eq!(positions(Feast::search("Chevl")), [(Month::Vendémiaire, 5)]);
+        assert_eq!(
+            positions(Feast::search("grape"))[0],
+            (Month::Vendémiaire, 1)
+        );
+        assert!(Feast::search("").is_empty());
+        assert!(Feast::search("xyzzy").is_empty());
+    }
+}
@@ -0,0 +1,498 @@
+//! Formatting and parsing of republican dates with `strftime`-like patterns.
+//!
+//! | Specifier | Example | Description |
+//! |-----------|---------|-------------|
+//! | `%A` | `Primidi` | Day of the decade |
+//! | `%u` | `01` | Day of the decade, from 1 to 10 |
+//! | `%d` | `01` | Day of the month, from 1 to 30 |
+//! | `%j` | `001` | Day of the year, from 1 to 366 |
+//! | `%D` | `1` | Decade of the month, from 1 to 3 |
+//! | `%B` | `Vendémiaire` | Month |
+//! | `%m` | `01` | Month, from 1 to 13 |
+//! | `%Y` | `1` | Year |
+//! | `%R` | `I` | Year in roman numerals, see [`roman`] |
+//! | `%F` | `1` | Franciade |
+//! | `%N` | `Raisin` | Feast of the day, translated if the locale has a glossary |
+//! | `%P` | `du Raisin` | Feast of the day, with its French article |
+//! | `%H` | `0` | Decimal hour, from 0 to 9 |
+//! | `%M` | `00` | Decimal minute, from 0 to 99 |
+//! | `%S` | `00` | Decimal second, from 0 to 99 |
+//! | `%f` | `000000000` | Nanoseconds |
+//! | `%%` | `%` | A literal `%` |
+//!
+//! Modifiers can be inserted after the `%`:
+//! `-` removes the padding of numbers, `_` pads them with spaces and `0` with zeros,
+//! `^` converts text to uppercase and `#` to lowercase.
+//!
+//! Names are written in French, or in the [`Locale`] given to the `format_with` methods.
+//! Formatting fails with [`std::fmt::Error`] if the pattern is invalid, or if it uses time specifiers on a [`Date`].
+//!
+//! The same patterns are used to parse dates with [`Date::parse_from_str`] and [`DateTime::parse_from_str`].
+
+use crate::roman::{parse_roman_year, to_roman_year_with};
+use crate::text::{strip_longest_prefix, strip_prefix_folded};
+use crate::*;
+use std::fmt::{self, Write};
+
+/// A date waiting to be formatted, returned by [`Date::format`] and [`DateTime::format`].
+#[derive(Clone)]
+pub struct DelayedFormat<'a> {
+    date: Date,
+    /// Hour, minute, second and nanosecond.
+    time: Option<(i64, i64, i64, i64)>,
+    pattern: &'a str,
+    locale: &'a dyn Locale,
+}
+
+impl<'a> DelayedFormat<'a> {
+    pub(crate) fn new(
+        date: Date,
+        time: Option<(i64, i64, i64, i64)>,
+        pattern: &'a str,
+        locale: &'a dyn Locale,
+    ) -> Self {
+        Self {
+            date,
+            time,
+            pattern,
+            locale,
+        }
+    }
+
+    fn time(&self) -> Result<(i64, i64, i64, i64), fmt::Error> {
+        self.time.ok_or(fmt::Error)
+    }
+}
+
+impl fmt::Debug for DelayedFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelayedFormat")
+            .field("date", &self.date)
+            .field("time", &self.time)
+            .field("pattern", &self.pattern)
+            .finish_non_exhaustive()
+    }
+}
+
+enum Padding {
+    None,
+    Space,
+    Zero,
+}
+
+enum Case {
+    Unchanged,
+    Upper,
+    Lower,
+}
+
+impl fmt::Display for DelayedFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = &self.date;
+        let mut chars = self.pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                f.write_char(c)?;
+                continue;
+            }
+
+            let mut padding = None;
+            let mut case = Case::Unchanged;
+            let specifier = loop {
+                match chars.next().ok_or(fmt::Error)? {
+                    '-' => padding = Some(Padding::None),
+                    '_' => padding = Some(Padding::Space),
+                    '0' => padding = Some(Padding::Zero),
+                    '^' => case = Case::Upper,
+                    '#' => case = Case::Lower,
+                    specifier => break specifier,
+                }
+            };
+
+            let number = |value: i64, width: usize| match padding.as_ref().unwrap_or(&Padding::Zero)
+            {
+                Padding::None => value.to_string(),
+                Padding::Space => format!("{value:width$}"),
+                Padding::Zero => format!("{value:0width$}"),
+            };
+            let text = match specifier {
+                'A' => self.locale.decade_day_name(date.decade_day()).to_string(),
+                'u' => number(date.num_decade_day(), 2),
+                'd' => number(date.day(), 2),
+                'j' => number(date.num_month0() * DAYS_PER_MONTH + date.day(), 3),
+                'D' => number(date.decade(), 1),
+                'B' => self.locale.month_name(date.month()).to_string(),
+                'm' => number(date.num_month(), 2),
+                'Y' => number(date.year(), 1),
+                'R' => to_roman_year_with(date.year(), self.locale.era_marker()),
+                'F' => number(date.franciade(), 1),
+                'N' => date.day_name_in(self.locale).to_string(),
+                'P' => date.day_name_with_article().to_string(),
+                'H' => number(self.time()?.0, 1),
+                'M' => number(self.time()?.1, 2),
+                'S' => number(self.time()?.2, 2),
+                'f' => number(self.time()?.3, 9),
+                '%' => String::from("%"),
+                _ => return Err(fmt::Error),
+            };
+            match case {
+                Case::Unchanged => f.write_str(&text)?,
+                Case::Upper => f.write_str(&text.to_uppercase())?,
+                Case::Lower => f.write_str(&text.to_lowercase())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fields read from an input by [`parse`].
+#[derive(Debug, Default)]
+pub(crate) struct Parsed {
+    year: Option<i64>,
+    month: Option<i64>,
+    day: Option<i64>,
+    day_of_year: Option<i64>,
+    decade: Option<i64>,
+    decade_day: Option<Day>,
+    num_decade_day: Option<i64>,
+    franciade: Option<i64>,
+    /// Month and day of the feast.
+    feast: Option<(i64, i64)>,
+    pub(crate) hour: i64,
+    pub(crate) minute: i64,
+    pub(crate) second: i64,
+    pub(crate) nanosecond: i64,
+}
+
+impl Parsed {
+    /// Builds the date, and checks that all the fields agree with it.
+    pub(crate) fn to_date(&self) -> Result<Date, Error> {
+        let year = self.year.ok_or(Error::InvalidFormat)?;
+        let (month, day) = match (self.month, self.day, self.day_of_year, self.feast) {
+            (Some(month), Some(day), _, _) => (month, day),
+            (_, _, Some(day_of_year), _) if day_of_year < 1 => return Err(Error::DayOutOfRange),
+            (_, _, Some(day_of_year), _) => (
+                (day_of_year - 1) / DAYS_PER_MONTH + 1,
+                (day_of_year - 1) % DAYS_PER_MONTH + 1,
+            ),
+            (_, _, _, Some(feast)) => feast,
+            _ => return Err(Error::InvalidFormat),
+        };
+        let date = Date::try_from_ymd(year, month, day)?;
+
+        let day_of_year = date.num_month0() * DAYS_PER_MONTH + date.day();
+        if self
+            .decade_day
+            .is_some_and(|decade_day| decade_day != date.decade_day())
+            || self
+                .num_decade_day
+                .is_some_and(|num| num != date.num_decade_day())
+        {
+            return Err(Error::DecadeDayMismatch);
+        }
+        if self.month.is_some_and(|month| month != date.num_month())
+            || self.day.is_some_and(|day| day != date.day())
+            || self.day_of_year.is_some_and(|doy| doy != day_of_year)
+            || self.decade.is_some_and(|decade| decade != date.decade())
+            || self
+                .franciade
+                .is_some_and(|franciade| franciade != date.franciade())
+            || self
+                .feast
+                .is_some_and(|feast| feast != (date.num_month(), date.day()))
+        {
+            return Err(Error::InconsistentFields);
+        }
+        Ok(date)
+    }
+}
+
+/// Reads an unsigned number of at most `max_digits` digits, after optional spaces.
+fn parse_number(input: &str, max_digits: usize) -> Result<(i64, usize, &str), Error> {
+    let input = input.trim_start_matches(' ');
+    let digits = input
+        .bytes()
+        .take(max_digits)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if digits == 0 {
+        return Err(Error::InvalidFormat);
+    }
+    let value = input[..digits].parse().map_err(|_| Error::InvalidFormat)?;
+    Ok((value, digits, &input[digits..]))
+}
+
+/// Reads a signed number, after optional spaces.
+fn parse_signed_number(input: &str) -> Result<(i64, &str), Error> {
+    let input = input.trim_start_matches(' ');
+    let (negative, input) = match input.strip_prefix('-') {
+        Some(input) => (true, input),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (value, _, input) = parse_number(input, 19)?;
+    Ok((if negative { -value } else { value }, input))
+}
+
+/// Returns whether the pattern has time specifiers and no date specifiers.
+fn is_time_pattern(pattern: &str) -> bool {
+    let mut has_time = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            match chars.find(|c| !matches!(c, '-' | '_' | '0' | '^' | '#')) {
+                Some('H' | 'M' | 'S' | 'f') => has_time = true,
+                Some('%') => (),
+                _ => return false,
+            }
+        }
+    }
+    has_time
+}
+
+/// Parses an input according to a pattern of the [`format`](self) module.
+///
+/// Names are matched regardless of case and accents, and whitespace in the pattern matches any amount of whitespace.
+/// If the input ends where the rest of the pattern only contains the time, it is ignored and the time is midnight.
+pub(crate) fn parse(mut input: &str, pattern: &str) -> Result<Parsed, Error> {
+    fn set<T: PartialEq>(field: &mut Option<T>, value: T) -> Result<(), Error> {
+        match field {
+            Some(previous) if *previous != value => Err(Error::InconsistentFields),
+            _ => {
+                *field = Some(value);
+                Ok(())
+            }
+        }
+    }
+
+    let mut parsed = Parsed::default();
+    let mut chars = pattern.chars();
+    loop {
+        if input.trim_start().is_empty() && is_time_pattern(chars.as_str()) {
+            break;
+        }
+        let Some(c) = chars.next() else {
+            break;
+        };
+        if c.is_whitespace() {
+            input = input.trim_start();
+            continue;
+        }
+        if c != '%' {
+            input = strip_prefix_folded(input, c.encode_utf8(&mut [0; 4]))
+                .ok_or(Error::InvalidFormat)?;
+            continue;
+        }
+
+        let specifier = chars
+            .by_ref()
+            .find(|c| !matches!(c, '-' | '_' | '0' | '^' | '#'))
+            .ok_or(Error::InvalidFormat)?;
+        input = match specifier {
+            'A' => {
+                let regular = (1..=10).map(|num| {
+                    let day = Day::Regular(RegularDay::from_num(num));
+                    (day, day.name())
+                });
+                let sansculottide = (1..=6).map(|num| {
+                    let day = Day::Sansculottide(SansculottideDay::from_num(num));
+                    (day, day.name())
+                });
+                let (day, input) = strip_longest_prefix(input, regular.chain(sansculottide))
+                    .ok_or(Error::InvalidFormat)?;
+                set(&mut parsed.decade_day, day)?;
+                input
+            }
+            'B' => {
+                let months = (1..=13).map(|num| (num, Month::from_num(num).name()));
+                let (month, input) =
+                    strip_longest_prefix(input, months).ok_or(Error::InvalidFormat)?;
+                set(&mut parsed.month, month)?;
+                input
+            }
+            'N' | 'P' => {
+                let feasts = (1..=13).flat_map(|month| {
+                    let days = if month == 13 { 6 } else { 30 };
+                    (1..=days).map(move |day| {
+                        let name = match specifier {
+                            'N' => day_name(Month::from_num(month), day),
+                            _ => day_name_with_article(Month::from_num(month), day),
+                        };
+                        ((month, day), name)
+                    })
+                });
+                let (feast, input) =
+                    strip_longest_prefix(input, feasts).ok_or(Error::InvalidFormat)?;
+                set(&mut parsed.feast, feast)?;
+                input
+            }
+            'R' => {
+                let (year, input) = parse_roman_year(input.trim_start_matches(' '))
+                    .ok_or(Error::InvalidFormat)?;
+                set(&mut parsed.year, year)?;
+                input
+            }
+            'Y' | 'F' => {
+                let (value, input) = parse_signed_number(input)?;
+                match specifier {
+                    'Y' => set(&mut parsed.year, value)?,
+                    _ => set(&mut parsed.franciade, value)?,
+                }
+                input
+            }
+            'f' => {
+                let (nanos, digits, input) = parse_number(input, 9)?;
+                parsed.nanosecond = nanos * 10i64.pow(9 - digits as u32);
+                input
+            }
+            'u' | 'd' | 'j' | 'D' | 'm' | 'H' | 'M' | 'S' => {
+                let max_digits = match specifier {
+                    'j' => 3,
+                    'D' | 'H' => 1,
+                    _ => 2,
+                };
+                let (value, _, input) = parse_number(input, max_digits)?;
+                match specifier {
+                    'u' => set(&mut parsed.num_decade_day, value)?,
+                    'd' => set(&mut parsed.day, value)?,
+                    'j' => set(&mut parsed.day_of_year, value)?,
+                    'D' => set(&mut parsed.decade, value)?,
+                    'm' => set(&mut parsed.month, value)?,
+                    'H' => parsed.hour = value,
+                    'M' => parsed.minute = value,
+                    _ => parsed.second = value,
+                }
+                input
+            }
+            '%' => input.strip_prefix('%').ok_or(Error::InvalidFormat)?,
+            _ => return Err(Error::InvalidFormat),
+        };
+    }
+    if !input.trim().is_empty() {
+        return Err(Error::InvalidFormat);
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let datetime = DateTime::from_ymd_hms(233, 2, 11, 3, 7, 4);
+        assert_eq!(
+            datetime.format("%A %d %B an %R, %Hh%M").to_string(),
+            "Primidi 11 Brumaire an CCXXXIII, 3h07"
+        );
+        assert_eq!(
+            datetime.format("%Y-%m-%d %H:%M:%S.%f").to_string(),
+            "233-02-11 3:07:04.000000000"
+        );
+        assert_eq!(datetime.format("%-m/%_M/%0H %%").to_string(), "2/ 7/3 %");
+        assert_eq!(
+            datetime.format("%^B %#A %D %u %j").to_string(),
+            "BRUMAIRE primidi 2 01 041"
+        );
+        assert_eq!(
+            datetime.format("%N, %P, franciade %F").to_string(),
+            "Salsifis, du Salsifis, franciade 59"
+        );
+
+        let date = Date::from_ymd(3, 13, 6);
+        assert_eq!(
+            date.format("%A %-d %B %Y").to_string(),
+            "Jour de la Révolution 6 Sansculotides 3"
+        );
+        assert_eq!(date.format("%R %j").to_string(), "III 366");
+
+        let mut s = String::new();
+        assert!(write!(s, "{}", date.format("%H")).is_err());
+        assert!(write!(s, "{}", date.format("%Q")).is_err());
+        assert!(write!(s, "{}", date.format("%")).is_err());
+        assert!(write!(s, "{}", datetime.format("%-")).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let date = Date::from_ymd(233, 2, 11);
+        let pattern = "%A %d %B an %R";
+        assert_eq!(
+            Date::parse_from_str("Primidi 11 Brumaire an CCXXXIII", pattern),
+            Ok(date.clone())
+        );
+        assert_eq!(
+            Date::parse_from_str("primidi  11 BRUMAIRE AN ccxxxiii", pattern),
+            Ok(date.clone())
+        );
+        assert_eq!(
+            Date::parse_from_str("11 Nivose 4", "%d %B %Y"),
+            Ok(Date::from_ymd(4, 4, 11))
+        );
+        assert_eq!(
+            Date::parse_from_str("Jour de l’opinion 4", "%A %Y"),
+            Err(Error::InvalidFormat)
+        );
+        assert_eq!(
+            Date::parse_from_str("Jour de l’opinion, 13/4/233", "%A, %m/%d/%Y"),
+            Ok(Date::from_ymd(233, 13, 4))
+        );
+        assert_eq!(Date::parse_from_str("041 233", "%j %Y"), Ok(date.clone()));
+        assert_eq!(
+            Date::parse_from_str("du Salsifis 233", "%P %Y"),
+            Ok(date.clone())
+        );
+        assert_eq!(
+            Date::parse_from_str("-5 1 1", "%Y %m %d").map(|d| d.year()),
+            Ok(-5)
+        );
+
+        assert_eq!(
+            Date::parse_from_str("Duodi 11 Brumaire an CCXXXIII", pattern),
+            Err(Error::DecadeDayMismatch)
+        );
+        assert_eq!(
+            Date::parse_from_str("071 2 233", "%j %m %Y"),
+            Err(Error::InconsistentFields)
+        );
+        assert_eq!(
+            Date::parse_from_str("11 Brumaire 233 1", "%d %B %Y"),
+            Err(Error::InvalidFormat)
+        );
+        assert_eq!(
+            Date::parse_from_str("11 Brumaire", "%d %B"),
+            Err(Error::InvalidFormat)
+        );
+        assert_eq!(
+            Date::parse_from_str("11 Brumaire 233", "%d %B %Q"),
+            Err(Error::InvalidFormat)
+        );
+        assert_eq!(
+            Date::parse_from_str("31 Brumaire 233", "%d %B %Y"),
+            Err(Error::DayOutOfRange)
+        );
+
+        let pattern = "%A %d %B an %R, %Hh%M";
+        assert_eq!(
+            DateTime::parse_from_str("Primidi 11 Brumaire an CCXXXIII, 3h07", pattern),
+            Ok(DateTime::from_ymd_hms(233, 2, 11, 3, 7, 0))
+        );
+        assert_eq!(
+            DateTime::parse_from_str("Primidi 11 Brumaire an CCXXXIII", pattern),
+            Ok(DateTime::from_ymd(233, 2, 11))
+        );
+        assert_eq!(
+            DateTime::parse_from_str("1 1 1 9:99:99.25", "%d %m %Y %H:%M:%S.%f")
+                .map(|d| d.nanosecond()),
+            Ok(250_000_000)
+        );
+        assert_eq!(
+            DateTime::parse_from_str("11 Brumaire 233 3x07", "%d %B %Y %Hh%M"),
+            Err(Error::InvalidFormat)
+        );
+
+        let datetime = DateTime::from_timestamp(Timestamp::from_nanos(123_456_789_123_456_789));
+        let pattern = "%A %u %D %d %B %m %j %Y %R %F %N %P %H %M %S %f %%";
+        let formatted = datetime.format(pattern).to_string();
+        assert_eq!(DateTime::parse_from_str(&formatted, pattern), Ok(datetime));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::*;
+
+/// A franciade, a period of 4 years.
+///
+/// As for [`Date::franciade`], the first franciade ends in year 3 and contains the years -1, 1, 2 and 3.
+/// Franciades are not defined by sextile years, so they don't all have `365*4+1` days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Franciade {
+    franciade0: i64,
+}
+
+impl Franciade {
+    /// # Panics
+    ///
+    /// Panics if the franciade is 0, there is no franciade 0 as there is no year 0.
+    pub fn new(franciade: i64) -> Self {
+        Self::try_new(franciade).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Same as [`Franciade::new`] but returns an error instead of panicking.
+    ///
+    /// Fails with [`Error::YearZero`] for the franciade 0.
+    pub fn try_new(franciade: i64) -> Result<Self, Error> {
+        if franciade == 0 {
+            return Err(Error::YearZero);
+        }
+        Ok(Self {
+            franciade0: republican_year0(franciade),
+        })
+    }
+
+    /// Returns the franciade number starting from 0.
+    pub fn franciade0(&self) -> i64 {
+        self.franciade0
+    }
+
+    /// Returns the franciade number starting from 1.
+    pub fn franciade(&self) -> i64 {
+        republican_year(self.franciade0)
+    }
+
+    /// Returns the 4 years of the franciade, in order.
+    pub fn years(&self) -> [i64; 4] {
+        let first_year0 = self.franciade0 * 4 - 1;
+        [0, 1, 2, 3].map(|i| republican_year(first_year0 + i))
+    }
+
+    pub fn first_day(&self) -> Date {
+        Date::from_ymd0(self.franciade0 * 4 - 1, 0, 0)
+    }
+
+    pub fn last_day(&self) -> Date {
+        Date::from_ymd0(self.franciade0 * 4 + 3, 0, 0) - Duration::from_days(1)
+    }
+
+    pub fn days(&self) -> DateRange {
+        DateRange::new(self.first_day(), self.last_day())
+    }
+
+    /// Returns the number of days of the 4 years, 1460 plus the number of sextile years.
+    pub fn num_days(&self) -> i64 {
+        (self.franciade0 * 4 - 1..self.franciade0 * 4 + 3)
+            .map(|year0| get_day_count0(year0, &Imcce))
+            .sum()
+    }
+
+    pub fn contains(&self, date: &Date) -> bool {
+        *self == Self::from(date)
+    }
+
+    pub fn next(&self) -> Self {
+        Self {
+            franciade0: self.franciade0 + 1,
+        }
+    }
+
+    pub fn prev(&self) -> Self {
+        Self {
+            franciade0: self.franciade0 - 1,
+        }
+    }
+}
+
+impl From<&Date> for Franciade {
+    fn from(date: &Date) -> Self {
+        Self {
+            franciade0: date.franciade0(),
+        }
+    }
+}
+
+impl From<Date> for Franciade {
+    fn from(date: Date) -> Self {
+        Self::from(&date)
+    }
+}
+
+/// Formats as `Franciade 1`.
+impl std::fmt::Display for Franciade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Franciade {}", self.franciade())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_franciade() {
+        let first = Franciade::from(Date::from_ymd(3, 13, 6));
+        assert_eq!(first, Franciade::new(1));
+        assert_eq!(first.to_string(), "Franciade 1");
+        assert_eq!(first.years(), [-1, 1, 2, 3]);
+        assert_eq!(first.first_day(), Date::from_ymd(-1, 1, 1));
+        assert_eq!(first.last_day(), Date::from_ymd(3, 13, 6));
+        assert_eq!(first.num_days(), first.days().len() as i64);
+        assert!(first.contains(&Date::from_ymd(-1, 5, 5)));
+        assert!(!first.contains(&Date::from_ymd(4, 1, 1)));
+        assert_eq!(first.prev(), Franciade::new(-1));
+        assert_eq!(first.prev().years(), [-5, -4, -3, -2]);
+        assert_eq!(first.next().years(), [4, 5, 6, 7]);
+        assert_eq!(first.next().num_days(), 1461);
+        for franciade in [-3, -1, 1, 58] {
+            let franciade = Franciade::new(franciade);
+            assert_eq!(Franciade::from(franciade.first_day()), franciade);
+            assert_eq!(Franciade::from(franciade.last_day()), franciade);
+            assert_eq!(
+                franciade.last_day() + Duration::from_days(1),
+                franciade.next().first_day()
+            );
+        }
+        assert_eq!(Franciade::try_new(0), Err(Error::YearZero));
+    }
+}
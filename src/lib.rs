@@ -1,23 +1,65 @@
+pub mod arithmetic;
+pub mod astronomy;
 pub mod date;
 pub mod datetime;
 pub mod day;
+pub mod decade;
+pub mod duration;
+pub mod error;
+pub mod feast;
+pub mod format;
+pub mod franciade;
+pub mod locale;
 pub mod months;
+pub mod offset;
+pub mod overflow;
+pub mod period;
+pub mod provider;
+pub mod range;
+pub mod roman;
+pub mod season;
+mod text;
 pub mod timestamp;
+pub mod year_info;
+pub mod year_month;
 pub mod years;
+pub use arithmetic::*;
+pub use astronomy::*;
 pub use date::*;
 pub use datetime::*;
 pub use day::*;
+pub use decade::*;
+pub use duration::*;
+pub use error::*;
+pub use feast::*;
+pub use format::DelayedFormat;
+pub use franciade::*;
+pub use locale::*;
 pub use months::*;
+pub use offset::*;
+pub use overflow::*;
+pub use period::*;
+pub use provider::*;
+pub use range::*;
+pub use season::*;
 pub use timestamp::*;
 pub mod day_names;
 pub mod equinoxes;
 pub use day_names::*;
 pub use equinoxes::*;
+pub use year_info::*;
+pub use year_month::*;
 pub use years::*;
 #[cfg(feature = "chrono")]
 pub mod chrono_compat;
+#[cfg(feature = "serde")]
+pub mod serde;
 
+pub(crate) const SECONDS_PER_MINUTE: i64 = 100;
+pub(crate) const SECONDS_PER_HOUR: i64 = 10000;
 pub(crate) const SECONDS_PER_DAY: i64 = 100000;
 pub(crate) const DAYS_PER_MONTH: i64 = 30;
 pub(crate) const DAYS_PER_DECADE: i64 = 10;
+/// 36 decades, and the Sansculotides as a shorter one.
+pub(crate) const DECADES_PER_YEAR: i64 = 37;
 pub(crate) const SECONDS_PER_MONTH: i64 = SECONDS_PER_DAY * DAYS_PER_MONTH;
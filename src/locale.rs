@@ -0,0 +1,456 @@
+use crate::roman::ERA_MARKER;
+use crate::{
+    day_name, day_name_en, Day, Month, PeriodNames, RegularDay, SansculottideDay, Season,
+};
+
+/// Names of the months, of the days of the decade, of the seasons and of the units of periods in a language.
+///
+/// The built-in locales are [`French`] (the default), [`English`], [`German`] and [`Italian`].
+/// Other languages can be supplied with a [`LocaleTable`], or by implementing this trait.
+/// Locales are used by the `localized` methods, for [`Display`](std::fmt::Display), and by the `format_with` methods.
+pub trait Locale {
+    fn month_name(&self, month: Month) -> &str;
+
+    fn regular_day_name(&self, day: RegularDay) -> &str;
+
+    fn sansculottide_day_name(&self, day: SansculottideDay) -> &str;
+
+    /// Returns the name of a day of the decade, or of a Sansculottide day.
+    fn decade_day_name(&self, day: Day) -> &str {
+        match day {
+            Day::Regular(day) => self.regular_day_name(day),
+            Day::Sansculottide(day) => self.sansculottide_day_name(day),
+        }
+    }
+
+    /// Returns the name of a season.
+    ///
+    /// Returns the French name by default.
+    fn season_name(&self, season: Season) -> &str {
+        season.name()
+    }
+
+    /// Returns the words used to write a [`Period`](crate::Period).
+    ///
+    /// Returns the French words by default.
+    fn period_names(&self) -> PeriodNames<'_> {
+        PeriodNames::FRENCH
+    }
+
+    /// Returns the word written before a year in roman numerals, with the alternate flag (`{:#}`).
+    ///
+    /// Returns `an` by default.
+    fn year_word(&self) -> &str {
+        "an"
+    }
+
+    /// Returns the words written after a year in roman numerals if it is before the Republic.
+    ///
+    /// Returns the French [`ERA_MARKER`] by default.
+    fn era_marker(&self) -> &str {
+        ERA_MARKER
+    }
+
+    /// Returns the translation of the name of the day (its feast), see [`day_name`].
+    ///
+    /// Returns `None` by default, for locales without a glossary of the feasts.
+    fn feast_name(&self, _month: Month, _num: i64) -> Option<&str> {
+        None
+    }
+}
+
+impl<L: Locale + ?Sized> Locale for &L {
+    fn month_name(&self, month: Month) -> &str {
+        (**self).month_name(month)
+    }
+
+    fn regular_day_name(&self, day: RegularDay) -> &str {
+        (**self).regular_day_name(day)
+    }
+
+    fn sansculottide_day_name(&self, day: SansculottideDay) -> &str {
+        (**self).sansculottide_day_name(day)
+    }
+
+    fn decade_day_name(&self, day: Day) -> &str {
+        (**self).decade_day_name(day)
+    }
+
+    fn season_name(&self, season: Season) -> &str {
+        (**self).season_name(season)
+    }
+
+    fn period_names(&self) -> PeriodNames<'_> {
+        (**self).period_names()
+    }
+
+    fn year_word(&self) -> &str {
+        (**self).year_word()
+    }
+
+    fn era_marker(&self) -> &str {
+        (**self).era_marker()
+    }
+
+    fn feast_name(&self, month: Month, num: i64) -> Option<&str> {
+        (**self).feast_name(month, num)
+    }
+}
+
+/// A locale defined by tables of names, in the order of the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocaleTable<'a> {
+    pub months: [&'a str; 13],
+    pub regular_days: [&'a str; 10],
+    pub sansculottide_days: [&'a str; 6],
+    pub seasons: [&'a str; 4],
+    pub periods: PeriodNames<'a>,
+    /// Written before a year in roman numerals.
+    pub year_word: &'a str,
+    /// Written after a year in roman numerals if it is before the Republic.
+    pub era_marker: &'a str,
+}
+
+impl Locale for LocaleTable<'_> {
+    fn month_name(&self, month: Month) -> &str {
+        self.months[month.num0() as usize]
+    }
+
+    fn regular_day_name(&self, day: RegularDay) -> &str {
+        self.regular_days[day.num0() as usize]
+    }
+
+    fn sansculottide_day_name(&self, day: SansculottideDay) -> &str {
+        self.sansculottide_days[day.num0() as usize]
+    }
+
+    fn season_name(&self, season: Season) -> &str {
+        self.seasons[season.num0() as usize]
+    }
+
+    fn period_names(&self) -> PeriodNames<'_> {
+        self.periods
+    }
+
+    fn year_word(&self) -> &str {
+        self.year_word
+    }
+
+    fn era_marker(&self) -> &str {
+        self.era_marker
+    }
+}
+
+/// The original names, as returned by [`Month::name`] and [`Day::name`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct French;
+
+impl Locale for French {
+    fn month_name(&self, month: Month) -> &str {
+        month.name()
+    }
+
+    fn regular_day_name(&self, day: RegularDay) -> &str {
+        day.name()
+    }
+
+    fn sansculottide_day_name(&self, day: SansculottideDay) -> &str {
+        day.name()
+    }
+
+    fn feast_name(&self, month: Month, num: i64) -> Option<&str> {
+        Some(day_name(month, num))
+    }
+}
+
+/// Defines a built-in locale from a [`LocaleTable`], and optionally a glossary of the feasts.
+macro_rules! table_locale {
+    ($(#[$attr:meta])* $name:ident, $table:expr $(, $feast_name:path)?) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl $name {
+            pub const TABLE: LocaleTable<'static> = $table;
+        }
+
+        impl Locale for $name {
+            fn month_name(&self, month: Month) -> &str {
+                Self::TABLE.month_name(month)
+            }
+
+            fn regular_day_name(&self, day: RegularDay) -> &str {
+                Self::TABLE.regular_day_name(day)
+            }
+
+            fn sansculottide_day_name(&self, day: SansculottideDay) -> &str {
+                Self::TABLE.sansculottide_day_name(day)
+            }
+
+            fn season_name(&self, season: Season) -> &str {
+                Self::TABLE.season_name(season)
+            }
+
+            fn period_names(&self) -> PeriodNames<'_> {
+                Self::TABLE.periods
+            }
+
+            fn year_word(&self) -> &str {
+                Self::TABLE.year_word
+            }
+
+            fn era_marker(&self) -> &str {
+                Self::TABLE.era_marker
+            }
+            $(
+                fn feast_name(&self, month: Month, num: i64) -> Option<&str> {
+                    Some($feast_name(month, num))
+                }
+            )?
+        }
+    };
+}
+
+table_locale!(
+    /// English names, with the traditional translations of the months and of the feasts.
+    English,
+    LocaleTable {
+        months: [
+            "Vintage", "Fog", "Frost", "Snow", "Rain", "Wind", "Seed", "Blossom", "Meadow",
+            "Harvest", "Heat", "Fruit", "Sansculottides",
+        ],
+        regular_days: [
+            "First Day", "Second Day", "Third Day", "Fourth Day", "Fifth Day", "Sixth Day",
+            "Seventh Day", "Eighth Day", "Ninth Day", "Tenth Day",
+        ],
+        sansculottide_days: [
+            "Virtue Day",
+            "Genius Day",
+            "Labour Day",
+            "Opinion Day",
+            "Rewards Day",
+            "Revolution Day",
+        ],
+        seasons: ["Autumn", "Winter", "Spring", "Summer"],
+        periods: PeriodNames {
+            units: [
+                ["year", "years"],
+                ["month", "months"],
+                ["decade", "decades"],
+                ["day", "days"],
+                ["hour", "hours"],
+                ["minute", "minutes"],
+                ["second", "seconds"],
+            ],
+            and: "and",
+            past: ["", " ago"],
+            future: ["in ", ""],
+        },
+        year_word: "year",
+        era_marker: "before the Republic",
+    },
+    day_name_en
+);
+
+table_locale!(
+    /// German names.
+    German,
+    LocaleTable {
+        months: [
+            "Weinmonat", "Nebelmonat", "Reifmonat", "Schneemonat", "Regenmonat", "Windmonat",
+            "Keimmonat", "Blütenmonat", "Wiesenmonat", "Erntemonat", "Hitzemonat", "Fruchtmonat",
+            "Sansculottiden",
+        ],
+        regular_days: [
+            "Erster Tag", "Zweiter Tag", "Dritter Tag", "Vierter Tag", "Fünfter Tag",
+            "Sechster Tag", "Siebter Tag", "Achter Tag", "Neunter Tag", "Zehnter Tag",
+        ],
+        sansculottide_days: [
+            "Tag der Tugend",
+            "Tag des Genies",
+            "Tag der Arbeit",
+            "Tag der Meinung",
+            "Tag der Belohnungen",
+            "Tag der Revolution",
+        ],
+        seasons: ["Herbst", "Winter", "Frühling", "Sommer"],
+        periods: PeriodNames {
+            units: [
+                ["Jahr", "Jahre"],
+                ["Monat", "Monate"],
+                ["Dekade", "Dekaden"],
+                ["Tag", "Tage"],
+                ["Stunde", "Stunden"],
+                ["Minute", "Minuten"],
+                ["Sekunde", "Sekunden"],
+            ],
+            and: "und",
+            past: ["", " her"],
+            future: ["noch ", ""],
+        },
+        year_word: "Jahr",
+        era_marker: "vor der Republik",
+    }
+);
+
+table_locale!(
+    /// Italian names.
+    Italian,
+    LocaleTable {
+        months: [
+            "Vendemmiaio", "Brumaio", "Frimaio", "Nevoso", "Piovoso", "Ventoso", "Germile",
+            "Fiorile", "Pratile", "Messidoro", "Termidoro", "Fruttidoro", "Sanculottidi",
+        ],
+        regular_days: [
+            "Primidì", "Duodì", "Tridì", "Quartidì", "Quintidì", "Sestidì", "Settidì", "Ottidì",
+            "Nonidì", "Decadì",
+        ],
+        sansculottide_days: [
+            "Giorno della virtù",
+            "Giorno del genio",
+            "Giorno del lavoro",
+            "Giorno dell'opinione",
+            "Giorno delle ricompense",
+            "Giorno della Rivoluzione",
+        ],
+        seasons: ["Autunno", "Inverno", "Primavera", "Estate"],
+        periods: PeriodNames {
+            units: [
+                ["anno", "anni"],
+                ["mese", "mesi"],
+                ["decade", "decadi"],
+                ["giorno", "giorni"],
+                ["ora", "ore"],
+                ["minuto", "minuti"],
+                ["secondo", "secondi"],
+            ],
+            and: "e",
+            past: ["", " fa"],
+            future: ["tra ", ""],
+        },
+        year_word: "anno",
+        era_marker: "prima della Repubblica",
+    }
+);
+
+/// A value displayed in a [`Locale`], returned by the `localized` methods.
+///
+/// Dates follow the forms of their own [`Display`](std::fmt::Display) implementation, including the alternate flag (`{:#}`).
+#[derive(Clone, Copy)]
+pub struct Localized<'a, T: ?Sized> {
+    pub(crate) value: &'a T,
+    pub(crate) locale: &'a dyn Locale,
+}
+
+impl<'a, T: ?Sized> Localized<'a, T> {
+    pub fn new(value: &'a T, locale: &'a dyn Locale) -> Self {
+        Self { value, locale }
+    }
+}
+
+impl<T: std::fmt::Debug + ?Sized> std::fmt::Debug for Localized<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Localized")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Display for Localized<'_, Month> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.locale.month_name(*self.value))
+    }
+}
+
+impl std::fmt::Display for Localized<'_, Day> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.locale.decade_day_name(*self.value))
+    }
+}
+
+impl std::fmt::Display for Localized<'_, Season> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.locale.season_name(*self.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_locales() {
+        assert_eq!(English.month_name(Month::Vendémiaire), "Vintage");
+        assert_eq!(German.month_name(Month::Brumaire), "Nebelmonat");
+        assert_eq!(Italian.month_name(Month::Frimaire), "Frimaio");
+        assert_eq!(French.month_name(Month::Nivôse), "Nivôse");
+        let day = Day::Sansculottide(SansculottideDay::Révolution);
+        assert_eq!(English.decade_day_name(day), "Revolution Day");
+        assert_eq!(Month::Brumaire.localized(&English).to_string(), "Fog");
+        assert_eq!(day.localized(&Italian).to_string(), "Giorno della Rivoluzione");
+
+        let date = Date::from_ymd(233, 2, 11);
+        assert_eq!(date.localized(&English).to_string(), "First Day 11 Fog 233");
+        assert_eq!(
+            format!("{:#}", date.localized(&German)),
+            "Erster Tag 11 Nebelmonat Jahr CCXXXIII"
+        );
+        assert_eq!(
+            format!("{:#}", Date::from_ymd(-5, 1, 1).localized(&English)),
+            "First Day 1 Vintage year V before the Republic"
+        );
+        assert_eq!(
+            format!("{:#}", DateTime::from_ymd(-5, 1, 1).localized(&Italian)),
+            "Primidì 1 Vendemmiaio anno V prima della Repubblica 0:00:00"
+        );
+        assert_eq!(date.localized(&French).to_string(), date.to_string());
+        let datetime = DateTime::from_ymd_hms(233, 2, 11, 3, 7, 4);
+        assert_eq!(datetime.localized(&Italian).to_string(), "Primidì 11 Brumaio 233 3:07:04");
+        assert_eq!(
+            datetime.format_with("%A, %d %B", &English).to_string(),
+            "First Day, 11 Fog"
+        );
+
+        assert_eq!(English.feast_name(Month::Vendémiaire, 1), Some("Grape"));
+        assert_eq!(German.feast_name(Month::Vendémiaire, 1), None);
+        assert_eq!(day_name_in(&English, Month::Frimaire, 28), "Truffle");
+        assert_eq!(day_name_in(&Italian, Month::Frimaire, 28), "Truffe");
+        assert_eq!(date.day_name_in(&English), "Salsify");
+        assert_eq!(datetime.format_with("%N", &English).to_string(), "Salsify");
+        assert_eq!(datetime.format_with("%P", &English).to_string(), "du Salsifis");
+        for month in (1..=13).map(Month::from_num) {
+            let days = if month == Month::Sansculotides { 6 } else { 30 };
+            assert!((1..=days).all(|day| !day_name_en(month, day).is_empty()));
+        }
+
+        let shouting = LocaleTable {
+            months: English::TABLE.months.map(|_| "MONTH"),
+            ..English::TABLE
+        };
+        assert_eq!(date.format_with("%B", &shouting).to_string(), "MONTH");
+
+        struct Decadi;
+
+        impl Locale for Decadi {
+            fn month_name(&self, month: Month) -> &str {
+                French.month_name(month)
+            }
+
+            fn regular_day_name(&self, day: RegularDay) -> &str {
+                French.regular_day_name(day)
+            }
+
+            fn sansculottide_day_name(&self, day: SansculottideDay) -> &str {
+                French.sansculottide_day_name(day)
+            }
+
+            fn decade_day_name(&self, _day: Day) -> &str {
+                "Décadi"
+            }
+        }
+        let by_ref: &dyn Locale = &&Decadi;
+        assert_eq!(by_ref.decade_day_name(date.decade_day()), "Décadi");
+        assert_eq!(date.format_with("%A", by_ref).to_string(), "Décadi");
+    }
+}
@@ -1,3 +1,6 @@
+use crate::text::fold;
+use crate::{Error, Locale, Localized, Season};
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Month {
     Vendémiaire,
@@ -16,6 +19,38 @@ pub enum Month {
 }
 
 impl Month {
+    /// All the months, in order.
+    pub const ALL: [Self; 13] = [
+        Self::Vendémiaire,
+        Self::Brumaire,
+        Self::Frimaire,
+        Self::Nivôse,
+        Self::Pluviôse,
+        Self::Ventôse,
+        Self::Germinal,
+        Self::Floréal,
+        Self::Prairial,
+        Self::Messidor,
+        Self::Thermidor,
+        Self::Fructidor,
+        Self::Sansculotides,
+    ];
+
+    /// Iterates over all the values, in order.
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the next value, wrapping around after the last one.
+    pub fn succ(&self) -> Self {
+        Self::ALL[(self.num0() as usize + 1) % Self::ALL.len()]
+    }
+
+    /// Returns the previous value, wrapping around before the first one.
+    pub fn pred(&self) -> Self {
+        Self::ALL[(self.num0() as usize + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             Month::Vendémiaire => "Vendémiaire",
@@ -34,6 +69,16 @@ impl Month {
         }
     }
 
+    /// Returns a [`Display`](std::fmt::Display) implementation with the name in a [`Locale`].
+    pub fn localized<'a>(&'a self, locale: &'a dyn Locale) -> Localized<'a, Self> {
+        Localized::new(self, locale)
+    }
+
+    /// Returns the season of the month, or `None` for the Sansculotides.
+    pub fn season(&self) -> Option<Season> {
+        Season::ALL.get(self.num0() as usize / 3).copied()
+    }
+
     pub fn name_lc(&self) -> &'static str {
         match self {
             Month::Vendémiaire => "vendémiaire",
@@ -76,9 +121,14 @@ impl Month {
 
     /// # Panics
     ///
-    /// Panics if `num0` is greater than 12.
+    /// Panics if `num0` is not in [0, 12].
     pub fn from_num0(num0: i64) -> Self {
-        match num0 {
+        Self::try_from_num0(num0).unwrap_or_else(|_| panic!("Invalid month number: {}", num0))
+    }
+
+    /// Same as [`Month::from_num0`] but returns an error instead of panicking.
+    pub fn try_from_num0(num0: i64) -> Result<Self, Error> {
+        Ok(match num0 {
             0 => Month::Vendémiaire,
             1 => Month::Brumaire,
             2 => Month::Frimaire,
@@ -92,16 +142,22 @@ impl Month {
             10 => Month::Thermidor,
             11 => Month::Fructidor,
             12 => Month::Sansculotides,
-            _ => panic!("Invalid month number: {}", num0),
-        }
+            _ => return Err(Error::Month0OutOfRange),
+        })
     }
 
     /// # Panics
     ///
-    /// Panics if `num` is greater than 11.
+    /// Panics if `num` is not in [1, 13].
     pub fn from_num(num: i64) -> Self {
         Self::from_num0(num - 1)
     }
+
+    /// Same as [`Month::from_num`] but returns an error instead of panicking.
+    pub fn try_from_num(num: i64) -> Result<Self, Error> {
+        let num0 = num.checked_sub(1).ok_or(Error::MonthOutOfRange)?;
+        Self::try_from_num0(num0).map_err(|_| Error::MonthOutOfRange)
+    }
 }
 
 impl std::fmt::Display for Month {
@@ -109,3 +165,62 @@ impl std::fmt::Display for Month {
         write!(f, "{}", self.name())
     }
 }
+
+impl TryFrom<i64> for Month {
+    type Error = Error;
+
+    /// Converts from the number starting from 1, see [`Month::try_from_num`].
+    fn try_from(num: i64) -> Result<Self, Self::Error> {
+        Self::try_from_num(num)
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = Error;
+
+    /// Converts from the number starting from 1, see [`Month::try_from_num`].
+    fn try_from(num: u8) -> Result<Self, Self::Error> {
+        Self::try_from_num(num as i64)
+    }
+}
+
+/// Parses the French name, regardless of case and accents.
+impl std::str::FromStr for Month {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = fold(s.trim());
+        Self::iter()
+            .find(|value| fold(value.name()) == name)
+            .ok_or(Error::InvalidFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Month::try_from(13u8), Ok(Month::Sansculotides));
+        assert_eq!(Month::try_from(1i64), Ok(Month::Vendémiaire));
+        assert_eq!(Month::try_from(0u8), Err(Error::MonthOutOfRange));
+        assert_eq!(Month::try_from(14u8), Err(Error::MonthOutOfRange));
+        assert_eq!(Month::try_from(i64::MIN), Err(Error::MonthOutOfRange));
+        assert_eq!(Month::try_from_num0(12), Ok(Month::Sansculotides));
+        assert_eq!(Month::try_from_num0(13), Err(Error::Month0OutOfRange));
+        assert_eq!(Month::try_from_num0(-1), Err(Error::Month0OutOfRange));
+
+        assert_eq!("Nivôse".parse(), Ok(Month::Nivôse));
+        assert_eq!("nivose".parse(), Ok(Month::Nivôse));
+        assert_eq!("FLOREAL".parse(), Ok(Month::Floréal));
+        assert_eq!("Brumidor".parse::<Month>(), Err(Error::InvalidFormat));
+
+        assert_eq!(Month::iter().len(), 13);
+        assert!(Month::iter().enumerate().all(|(i, month)| month.num0() == i as i64));
+        assert_eq!(Month::Vendémiaire.succ(), Month::Brumaire);
+        assert_eq!(Month::Sansculotides.succ(), Month::Vendémiaire);
+        assert_eq!(Month::Vendémiaire.pred(), Month::Sansculotides);
+        assert!(Month::iter().all(|month| month.succ().pred() == month));
+    }
+}
@@ -0,0 +1,46 @@
+/// The time offset between Universal Time and the time of the Republic, in gregorian seconds.
+///
+/// It is only used when converting from and to unix time.
+/// Year starts are always computed with the time of the Paris observatory used by the decree.
+///
+/// The default offset is chosen at compile time with the `no-time-offset` and `average-time-offset` features,
+/// but any offset can be passed explicitly to the `_with` conversion methods, such as [`Timestamp::from_unix_with`](crate::Timestamp::from_unix_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Offset {
+    /// 1080 seconds, from article I of the decree.
+    ArticleOne,
+    /// 1029 seconds, the average of the offsets given by articles I and V.
+    Average,
+    /// No offset at all.
+    Zero,
+    /// Any number of gregorian seconds.
+    Custom(i64),
+}
+
+impl Offset {
+    /// The offset used by conversions that don't take one explicitly.
+    #[cfg(not(any(feature = "no-time-offset", feature = "average-time-offset")))]
+    pub const DEFAULT: Offset = Offset::ArticleOne;
+    /// The offset used by conversions that don't take one explicitly.
+    #[cfg(feature = "no-time-offset")]
+    pub const DEFAULT: Offset = Offset::Zero;
+    /// The offset used by conversions that don't take one explicitly.
+    #[cfg(feature = "average-time-offset")]
+    pub const DEFAULT: Offset = Offset::Average;
+
+    /// Returns the offset in gregorian seconds.
+    pub const fn gregorian_seconds(&self) -> i64 {
+        match self {
+            Offset::ArticleOne => 1080,
+            Offset::Average => 1029,
+            Offset::Zero => 0,
+            Offset::Custom(seconds) => *seconds,
+        }
+    }
+}
+
+impl Default for Offset {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,338 @@
+use crate::*;
+
+const MONTHS_PER_YEAR: i64 = 13;
+
+/// Returns the number of decades between the start of the year 1 and the decade of the date,
+/// the Sansculotides counting as the last decade of their year.
+pub(crate) fn decade_index(date: &Date) -> i64 {
+    let decade_in_year = match date.month() {
+        Month::Sansculotides => DECADES_PER_YEAR - 1,
+        _ => date.num_month0() * 3 + date.decade0(),
+    };
+    date.year0() * DECADES_PER_YEAR + decade_in_year
+}
+
+/// What to do when adding months, years or decades to a [`Date`] lands beyond the last Sansculottide day of the year.
+///
+/// For instance, the 30th Fructidor plus a month would be the 30th Sansculottide day,
+/// and the 6th Sansculottide day of a sextile year plus a year doesn't exist in non-sextile years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Stops at the last Sansculottide day of the year.
+    Clamp,
+    /// Continues into the next year, like [`Date::from_ymd_normalized`].
+    RollOver,
+    /// Fails with [`Error::SansculottideDayOutOfRange`].
+    Error,
+}
+
+impl Date {
+    fn try_from_ymd0_overflowing<P: YearStartProvider + ?Sized>(
+        year0: i64,
+        month0: i64,
+        day0: i64,
+        overflow: Overflow,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        match Self::try_from_ymd0_with(year0, month0, day0, provider) {
+            Err(Error::SansculottideDayOutOfRange) => {
+                let sansculottides = get_day_count0(year0, provider) - 12 * DAYS_PER_MONTH;
+                match overflow {
+                    Overflow::Clamp => {
+                        Self::try_from_ymd0_with(year0, month0, sansculottides - 1, provider)
+                    }
+                    Overflow::RollOver => {
+                        Self::try_from_ymd0_with(year0 + 1, 0, day0 - sansculottides, provider)
+                    }
+                    Overflow::Error => Err(Error::SansculottideDayOutOfRange),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Adds months, keeping the day of the month.
+    ///
+    /// The Sansculotides count as a 13th month, so the 1st Fructidor plus a month is the 1st Sansculottide day.
+    /// Days beyond the end of the Sansculotides are handled according to `overflow`.
+    pub fn try_add_months(&self, months: i64, overflow: Overflow) -> Result<Self, Error> {
+        self.try_add_months_with(months, overflow, &Imcce)
+    }
+
+    /// Same as [`Date::try_add_months`] with an explicit [`YearStartProvider`], which decides whether the year is sextile.
+    pub fn try_add_months_with<P: YearStartProvider + ?Sized>(
+        &self,
+        months: i64,
+        overflow: Overflow,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        let month = (self.year0().checked_mul(MONTHS_PER_YEAR))
+            .and_then(|month| month.checked_add(self.num_month0()))
+            .and_then(|month| month.checked_add(months))
+            .ok_or(Error::TimestampOutOfRange)?;
+        Self::try_from_ymd0_overflowing(
+            month.div_euclid(MONTHS_PER_YEAR),
+            month.rem_euclid(MONTHS_PER_YEAR),
+            self.day0(),
+            overflow,
+            provider,
+        )
+    }
+
+    /// Adds years, keeping the month and the day.
+    ///
+    /// The 6th Sansculottide day is handled according to `overflow` when the resulting year is not sextile.
+    pub fn try_add_years(&self, years: i64, overflow: Overflow) -> Result<Self, Error> {
+        self.try_add_years_with(years, overflow, &Imcce)
+    }
+
+    /// Same as [`Date::try_add_years`] with an explicit [`YearStartProvider`], which decides whether the year is sextile.
+    pub fn try_add_years_with<P: YearStartProvider + ?Sized>(
+        &self,
+        years: i64,
+        overflow: Overflow,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        let year0 = self
+            .year0()
+            .checked_add(years)
+            .ok_or(Error::TimestampOutOfRange)?;
+        Self::try_from_ymd0_overflowing(year0, self.num_month0(), self.day0(), overflow, provider)
+    }
+
+    /// Adds decades, keeping the day of the decade.
+    ///
+    /// Each year has 36 decades, followed by the Sansculotides which count as a 37th decade of 5 or 6 days.
+    /// Days beyond the end of the Sansculotides are handled according to `overflow`.
+    pub fn try_add_decades(&self, decades: i64, overflow: Overflow) -> Result<Self, Error> {
+        self.try_add_decades_with(decades, overflow, &Imcce)
+    }
+
+    /// Same as [`Date::try_add_decades`] with an explicit [`YearStartProvider`], which decides whether the year is sextile.
+    pub fn try_add_decades_with<P: YearStartProvider + ?Sized>(
+        &self,
+        decades: i64,
+        overflow: Overflow,
+        provider: &P,
+    ) -> Result<Self, Error> {
+        let decade = decade_index(self)
+            .checked_add(decades)
+            .ok_or(Error::TimestampOutOfRange)?;
+        let year0 = decade.div_euclid(DECADES_PER_YEAR);
+        let decade_in_year = decade.rem_euclid(DECADES_PER_YEAR);
+        let day_in_decade0 = self.num_decade_day0();
+        match decade_in_year {
+            36 => Self::try_from_ymd0_overflowing(year0, 12, day_in_decade0, overflow, provider),
+            _ => Self::try_from_ymd0_overflowing(
+                year0,
+                decade_in_year / 3,
+                decade_in_year % 3 * DAYS_PER_DECADE + day_in_decade0,
+                overflow,
+                provider,
+            ),
+        }
+    }
+
+    /// Same as [`Date::try_add_months`] with a negative number of months.
+    pub fn try_sub_months(&self, months: i64, overflow: Overflow) -> Result<Self, Error> {
+        self.try_add_months(
+            months.checked_neg().ok_or(Error::TimestampOutOfRange)?,
+            overflow,
+        )
+    }
+
+    /// Same as [`Date::try_add_years`] with a negative number of years.
+    pub fn try_sub_years(&self, years: i64, overflow: Overflow) -> Result<Self, Error> {
+        self.try_add_years(
+            years.checked_neg().ok_or(Error::TimestampOutOfRange)?,
+            overflow,
+        )
+    }
+
+    /// Same as [`Date::try_add_decades`] with a negative number of decades.
+    pub fn try_sub_decades(&self, decades: i64, overflow: Overflow) -> Result<Self, Error> {
+        self.try_add_decades(
+            decades.checked_neg().ok_or(Error::TimestampOutOfRange)?,
+            overflow,
+        )
+    }
+
+    /// Same as [`Date::try_add_months`] but returns `None` instead of an error.
+    pub fn checked_add_months(&self, months: i64, overflow: Overflow) -> Option<Self> {
+        self.try_add_months(months, overflow).ok()
+    }
+
+    /// Same as [`Date::try_add_years`] but returns `None` instead of an error.
+    pub fn checked_add_years(&self, years: i64, overflow: Overflow) -> Option<Self> {
+        self.try_add_years(years, overflow).ok()
+    }
+
+    /// Same as [`Date::try_add_decades`] but returns `None` instead of an error.
+    pub fn checked_add_decades(&self, decades: i64, overflow: Overflow) -> Option<Self> {
+        self.try_add_decades(decades, overflow).ok()
+    }
+
+    /// Same as [`Date::try_sub_months`] but returns `None` instead of an error.
+    pub fn checked_sub_months(&self, months: i64, overflow: Overflow) -> Option<Self> {
+        self.try_sub_months(months, overflow).ok()
+    }
+
+    /// Same as [`Date::try_sub_years`] but returns `None` instead of an error.
+    pub fn checked_sub_years(&self, years: i64, overflow: Overflow) -> Option<Self> {
+        self.try_sub_years(years, overflow).ok()
+    }
+
+    /// Same as [`Date::try_sub_decades`] but returns `None` instead of an error.
+    pub fn checked_sub_decades(&self, decades: i64, overflow: Overflow) -> Option<Self> {
+        self.try_sub_decades(decades, overflow).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_months() {
+        let date = Date::from_ymd(1, 12, 3);
+        assert_eq!(
+            date.try_add_months(1, Overflow::Error),
+            Ok(Date::from_ymd(1, 13, 3))
+        );
+        assert_eq!(
+            date.try_add_months(2, Overflow::Error),
+            Ok(Date::from_ymd(2, 1, 3))
+        );
+        assert_eq!(
+            date.try_sub_months(12, Overflow::Error),
+            Ok(Date::from_ymd(-1, 13, 3))
+        );
+        assert_eq!(
+            date.try_add_months(-13 * 2, Overflow::Error),
+            Ok(Date::from_ymd(-2, 12, 3))
+        );
+
+        let date = Date::from_ymd(3, 12, 30);
+        assert_eq!(
+            date.checked_add_months(1, Overflow::Clamp),
+            Some(Date::from_ymd(3, 13, 6))
+        );
+        assert_eq!(
+            date.checked_add_months(14, Overflow::Clamp),
+            Some(Date::from_ymd(4, 13, 5))
+        );
+        assert_eq!(
+            date.checked_add_months(1, Overflow::RollOver),
+            Some(Date::from_ymd(4, 1, 24))
+        );
+        assert_eq!(
+            date.checked_add_months(14, Overflow::RollOver),
+            Some(Date::from_ymd(5, 1, 25))
+        );
+        assert_eq!(
+            date.try_add_months(1, Overflow::Error),
+            Err(Error::SansculottideDayOutOfRange)
+        );
+        assert_eq!(
+            date.try_add_months(i64::MAX, Overflow::Clamp),
+            Err(Error::TimestampOutOfRange)
+        );
+        assert_eq!(
+            date.try_sub_months(i64::MIN, Overflow::Clamp),
+            Err(Error::TimestampOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_provider() {
+        // The year 19 is sextile in the arithmetic calendar only
+        let date = Date::from_ymd(19, 12, 26);
+        assert_eq!(
+            date.try_add_months(1, Overflow::Clamp),
+            Ok(Date::from_ymd(19, 13, 5))
+        );
+        assert_eq!(
+            date.try_add_months_with(1, Overflow::Clamp, &Arithmetic),
+            Date::try_from_ymd_with(19, 13, 6, &Arithmetic)
+        );
+        assert_eq!(
+            date.try_add_months(1, Overflow::RollOver),
+            Ok(Date::from_ymd(20, 1, 21))
+        );
+        assert_eq!(
+            date.try_add_months_with(1, Overflow::RollOver, &Arithmetic),
+            Ok(Date::from_ymd(20, 1, 20))
+        );
+        let revolution = Date::try_from_ymd_with(15, 13, 6, &Arithmetic).unwrap();
+        assert_eq!(
+            revolution.try_add_years_with(4, Overflow::Error, &Arithmetic),
+            Date::try_from_ymd_with(19, 13, 6, &Arithmetic)
+        );
+        assert_eq!(
+            date.try_add_decades_with(1, Overflow::Error, &Arithmetic),
+            Date::try_from_ymd_with(19, 13, 6, &Arithmetic)
+        );
+    }
+
+    #[test]
+    fn test_add_years() {
+        let revolution = Date::from_ymd(3, 13, 6);
+        assert_eq!(
+            revolution.checked_add_years(4, Overflow::Error),
+            Some(Date::from_ymd(7, 13, 6))
+        );
+        assert_eq!(
+            revolution.checked_add_years(1, Overflow::Clamp),
+            Some(Date::from_ymd(4, 13, 5))
+        );
+        assert_eq!(
+            revolution.checked_add_years(1, Overflow::RollOver),
+            Some(Date::from_ymd(5, 1, 1))
+        );
+        assert_eq!(revolution.checked_add_years(1, Overflow::Error), None);
+        assert_eq!(
+            revolution.checked_sub_years(3, Overflow::Clamp),
+            Some(Date::from_ymd(-1, 13, 5))
+        );
+        assert_eq!(
+            Date::from_ymd(233, 2, 11).checked_sub_years(233, Overflow::Error),
+            Some(Date::from_ymd(-1, 2, 11))
+        );
+    }
+
+    #[test]
+    fn test_add_decades() {
+        let date = Date::from_ymd(1, 12, 26);
+        assert_eq!(date.checked_add_decades(1, Overflow::Error), None);
+        assert_eq!(
+            date.checked_add_decades(1, Overflow::Clamp),
+            Some(Date::from_ymd(1, 13, 5))
+        );
+        assert_eq!(
+            date.checked_add_decades(1, Overflow::RollOver),
+            Some(Date::from_ymd(2, 1, 1))
+        );
+        assert_eq!(
+            date.checked_add_decades(2, Overflow::Error),
+            Some(Date::from_ymd(2, 1, 6))
+        );
+        assert_eq!(
+            date.checked_sub_decades(35, Overflow::Error),
+            Some(Date::from_ymd(1, 1, 6))
+        );
+
+        let sansculottide = Date::from_ymd(1, 13, 2);
+        assert_eq!(
+            sansculottide.checked_add_decades(1, Overflow::Error),
+            Some(Date::from_ymd(2, 1, 2))
+        );
+        assert_eq!(
+            sansculottide.checked_sub_decades(1, Overflow::Error),
+            Some(Date::from_ymd(1, 12, 22))
+        );
+        assert_eq!(
+            sansculottide.checked_add_decades(37, Overflow::Error),
+            Some(Date::from_ymd(2, 13, 2))
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use crate::*;
+
+/// The difference between two dates, in republican years, months, decades, days, hours, minutes and seconds.
+///
+/// Months, decades and days are counted on the calendar, as by [`Date::try_add_months`] and [`Date::try_add_decades`]:
+/// the Sansculotides are a 13th month and a 37th decade of 5 or 6 days, and dates beyond them roll over to the next year.
+/// All the fields have the sign of the period, and fractions of seconds are dropped.
+///
+/// Periods are written in French, or in the [`Locale`] given to [`Period::localized`], such as `2 décades et 3 jours`.
+/// The alternate flag (`{:#}`) writes how long ago the start was, seen from the end, such as `il y a 2 décades et 3 jours`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Period {
+    pub years: i64,
+    pub months: i64,
+    pub decades: i64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+}
+
+impl Period {
+    /// Returns the period from `start` to `end`, negative if `end` is before `start`.
+    ///
+    /// The age of someone is `Period::between(&birth, &today).years`.
+    pub fn between(start: &Date, end: &Date) -> Self {
+        if end < start {
+            return -Self::between(end, start);
+        }
+        let add_months = |months| start.try_add_months(months, Overflow::RollOver).ok();
+        let mut months = (end.year0() - start.year0()) * 13 + end.num_month0() - start.num_month0();
+        while add_months(months).is_none_or(|date| date > *end) {
+            months -= 1;
+        }
+        let base = add_months(months).unwrap_or_else(|| start.clone());
+        let add_decades = |decades| base.try_add_decades(decades, Overflow::RollOver).ok();
+        let mut decades = 0;
+        while add_decades(decades + 1).is_some_and(|date| date <= *end) {
+            decades += 1;
+        }
+        let base = add_decades(decades).unwrap_or(base);
+
+        Self {
+            years: months / 13,
+            months: months % 13,
+            decades,
+            days: (end.clone() - base).num_days() as i64,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the period from `start` to `end`, negative if `end` is before `start`.
+    pub fn between_datetimes(start: &DateTime, end: &DateTime) -> Self {
+        if end < start {
+            return -Self::between_datetimes(end, start);
+        }
+        let time_of_day = |datetime: &DateTime| {
+            (datetime.hour() * SECONDS_PER_HOUR
+                + datetime.minute() * SECONDS_PER_MINUTE
+                + datetime.second()) as i128
+                * NANOS_PER_SECOND
+                + datetime.nanosecond() as i128
+        };
+        let mut nanos = time_of_day(end) - time_of_day(start);
+        let mut end_date = end.date();
+        if nanos < 0 {
+            nanos += Duration::from_days(1).as_nanos();
+            end_date -= Duration::from_days(1);
+        }
+        let seconds = Duration::from_nanos(nanos).num_seconds() as i64;
+        Self {
+            hours: seconds / SECONDS_PER_HOUR,
+            minutes: seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE,
+            seconds: seconds % SECONDS_PER_MINUTE,
+            ..Self::between(&start.date(), &end_date)
+        }
+    }
+
+    /// Returns the fields from years to seconds.
+    fn fields(&self) -> [i64; 7] {
+        [
+            self.years,
+            self.months,
+            self.decades,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+        ]
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.fields() == [0; 7]
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.fields().iter().any(|field| *field < 0)
+    }
+
+    /// Returns a [`Display`](std::fmt::Display) implementation in a [`Locale`].
+    pub fn localized<'a>(&'a self, locale: &'a dyn Locale) -> Localized<'a, Self> {
+        Localized::new(self, locale)
+    }
+}
+
+impl std::ops::Neg for Period {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            years: -self.years,
+            months: -self.months,
+            decades: -self.decades,
+            days: -self.days,
+            hours: -self.hours,
+            minutes: -self.minutes,
+            seconds: -self.seconds,
+        }
+    }
+}
+
+/// Words used to write a [`Period`] in a [`Locale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PeriodNames<'a> {
+    /// Singular and plural of years, months, decades, days, hours, minutes and seconds.
+    pub units: [[&'a str; 2]; 7],
+    /// Joins the last two units.
+    pub and: &'a str,
+    /// Written before and after a period in the past.
+    pub past: [&'a str; 2],
+    /// Written before and after a period in the future.
+    pub future: [&'a str; 2],
+}
+
+impl PeriodNames<'static> {
+    pub const FRENCH: Self = PeriodNames {
+        units: [
+            ["an", "ans"],
+            ["mois", "mois"],
+            ["décade", "décades"],
+            ["jour", "jours"],
+            ["heure", "heures"],
+            ["minute", "minutes"],
+            ["seconde", "secondes"],
+        ],
+        and: "et",
+        past: ["il y a ", ""],
+        future: ["dans ", ""],
+    };
+}
+
+impl std::fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.localized(&French), f)
+    }
+}
+
+impl std::fmt::Display for Localized<'_, Period> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = self.locale.period_names();
+        let negative = self.value.is_negative();
+        let [before, after] = match (f.alternate(), negative) {
+            (false, false) => ["", ""],
+            (false, true) => ["-", ""],
+            (true, false) => names.past,
+            (true, true) => names.future,
+        };
+        let units = (self.value.fields().into_iter().zip(names.units))
+            .filter(|(count, _)| *count != 0)
+            .collect::<Vec<_>>();
+        f.write_str(before)?;
+        if units.is_empty() {
+            write!(f, "0 {}", names.units[3][1])?;
+        }
+        for (i, (count, [singular, plural])) in units.iter().enumerate() {
+            match i {
+                0 => (),
+                i if i == units.len() - 1 => write!(f, " {} ", names.and)?,
+                _ => f.write_str(", ")?,
+            }
+            let name = if count.abs() == 1 { singular } else { plural };
+            write!(f, "{} {}", count.abs(), name)?;
+        }
+        f.write_str(after)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_period() {
+        let birth = Date::from_ymd(3, 13, 6);
+        let period = Period::between(&birth, &Date::from_ymd(233, 2, 11));
+        assert_eq!(
+            (period.years, period.months, period.decades, period.days),
+            (229, 2, 0, 5)
+        );
+        assert_eq!(Period::between(&birth, &Date::from_ymd(4, 13, 5)).years, 0);
+        assert_eq!(Period::between(&birth, &Date::from_ymd(5, 1, 1)).years, 1);
+        assert_eq!(Period::between(&birth, &Date::from_ymd(7, 13, 6)).years, 4);
+
+        let start = Date::from_ymd(1, 12, 26);
+        let period = Period::between(&start, &Date::from_ymd(2, 1, 3));
+        assert_eq!((period.months, period.decades, period.days), (0, 1, 2));
+        let period = Period::between(&Date::from_ymd(2, 1, 3), &start);
+        assert_eq!((period.months, period.decades, period.days), (0, -1, -2));
+        assert_eq!(Period::between(&start, &start), Period::default());
+        for (start, end) in [
+            ((1, 1, 1), (1, 13, 5)),
+            ((3, 4, 29), (8, 2, 1)),
+            ((-3, 13, 2), (2, 13, 1)),
+        ] {
+            let start = Date::from_ymd(start.0, start.1, start.2);
+            let end = Date::from_ymd(end.0, end.1, end.2);
+            let period = Period::between(&start, &end);
+            let rebuilt = start
+                .try_add_months(period.years * 13 + period.months, Overflow::RollOver)
+                .and_then(|date| date.try_add_decades(period.decades, Overflow::RollOver))
+                .map(|date| date + Duration::from_days(period.days));
+            assert_eq!(rebuilt, Ok(end));
+        }
+
+        let start = DateTime::from_ymd_hms(233, 2, 11, 8, 50, 0);
+        let end = DateTime::from_ymd_hms(233, 2, 23, 1, 2, 3);
+        let period = Period::between_datetimes(&start, &end);
+        assert_eq!(
+            (
+                period.decades,
+                period.days,
+                period.hours,
+                period.minutes,
+                period.seconds
+            ),
+            (1, 1, 2, 52, 3)
+        );
+        let end = "23 Brumaire 233 8:50:00.5".parse::<DateTime>().unwrap();
+        let period = Period::between_datetimes(&end, &start);
+        assert_eq!((period.decades, period.days, period.seconds), (-1, -2, 0));
+
+        let period = Period {
+            decades: 2,
+            days: 3,
+            ..Period::default()
+        };
+        assert_eq!(period.to_string(), "2 décades et 3 jours");
+        assert_eq!(format!("{period:#}"), "il y a 2 décades et 3 jours");
+        assert_eq!(format!("{:#}", -period), "dans 2 décades et 3 jours");
+        assert_eq!((-period).to_string(), "-2 décades et 3 jours");
+        let period = Period {
+            years: 1,
+            months: 2,
+            hours: 1,
+            ..Period::default()
+        };
+        assert_eq!(
+            period.localized(&English).to_string(),
+            "1 year, 2 months and 1 hour"
+        );
+        assert_eq!(
+            format!("{:#}", period.localized(&English)),
+            "1 year, 2 months and 1 hour ago"
+        );
+        assert_eq!(
+            format!("{:#}", period.localized(&Italian)),
+            "1 anno, 2 mesi e 1 ora fa"
+        );
+        assert_eq!(Period::default().to_string(), "0 jours");
+    }
+}
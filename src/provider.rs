@@ -0,0 +1,48 @@
+use crate::{astronomy::compute_equinox0, years::year_start0, REPUBLICAN_SECONDS_PER_DAY};
+
+/// A source of truth for the first day of each republican year.
+///
+/// Functions such as [`ts_to_year0_with`](crate::ts_to_year0_with), [`get_day_count_with`](crate::get_day_count_with)
+/// or [`Date::from_timestamp_with`](crate::Date::from_timestamp_with) can be parameterised over it.
+/// Their counterparts without the `_with` suffix use [`Imcce`].
+pub trait YearStartProvider {
+    /// Returns the timestamp of the midnight starting the year (starting from 0).
+    ///
+    /// It is an `i128` so that any year can be answered without overflowing.
+    /// Implementations must be increasing, and should return multiples of 100000 (midnight) and years of 365 or 366 days.
+    fn year_start0(&self, republican_year0: i64) -> i128;
+}
+
+impl<P: YearStartProvider + ?Sized> YearStartProvider for &P {
+    fn year_start0(&self, republican_year0: i64) -> i128 {
+        (**self).year_start0(republican_year0)
+    }
+}
+
+/// The equinoxes collected by the Observatoire de Paris (IMCCE), from 1583 to 2999.
+///
+/// Outside of this range, a sextile year is added every 4 years,
+/// or the equinox is computed if the `astronomical` feature is enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Imcce;
+
+impl YearStartProvider for Imcce {
+    fn year_start0(&self, republican_year0: i64) -> i128 {
+        year_start0(republican_year0)
+    }
+}
+
+/// Equinoxes computed with Meeus' algorithm for every year, see [`compute_equinox`](crate::compute_equinox).
+///
+/// Years only start on the right day from gregorian year -1000 to 3000 (republican years -2792 to 1209),
+/// where the algorithm is accurate to about a minute.
+/// Further away, the equinoxes are extrapolated with a mean tropical year, and years may start days too early or too late.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Astronomical;
+
+impl YearStartProvider for Astronomical {
+    fn year_start0(&self, republican_year0: i64) -> i128 {
+        let equinox = compute_equinox0(republican_year0);
+        equinox - equinox.rem_euclid(REPUBLICAN_SECONDS_PER_DAY as i128)
+    }
+}
@@ -0,0 +1,231 @@
+use crate::*;
+use std::iter::FusedIterator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Step {
+    Days(i64),
+    Decades(i64),
+}
+
+/// The dates from a start to an end (both included), one day apart or with a step in days or decades.
+///
+/// Decades are counted on the calendar as by [`Date::try_add_decades`], keeping the day of the decade:
+/// the Sansculotides count as a decade, and Sansculottide days beyond the end of the year are clamped to the last one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: Date,
+    step: Step,
+    /// Index of the next date from the front.
+    front: i64,
+    /// Index after the next date from the back.
+    back: i64,
+}
+
+impl DateRange {
+    /// Returns the dates from `start` to `end`, both included, or no date if `end` is before `start`.
+    pub fn new(start: Date, end: Date) -> Self {
+        let back = match end < start {
+            true => 0,
+            false => (end - start.clone()).num_days() as i64 + 1,
+        };
+        Self {
+            start,
+            step: Step::Days(1),
+            front: 0,
+            back,
+        }
+    }
+
+    /// Returns the last date of the range, which might be before the end it was created with because of the step.
+    fn last_date(&self) -> Option<Date> {
+        (self.front < self.back).then(|| self.nth_date(self.back - 1))
+    }
+
+    /// Keeps the dates that are a multiple of `days` days after the start.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days` is not positive.
+    pub fn step_days(self, days: i64) -> Self {
+        assert!(days > 0, "step must be positive");
+        let Some(end) = self.last_date() else {
+            return self;
+        };
+        let start = self.nth_date(self.front);
+        Self {
+            back: (end - start.clone()).num_days() as i64 / days + 1,
+            start,
+            step: Step::Days(days),
+            front: 0,
+        }
+    }
+
+    /// Keeps the same day of the decade every `decades` decades after the start.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decades` is not positive.
+    pub fn step_decades(self, decades: i64) -> Self {
+        assert!(decades > 0, "step must be positive");
+        let Some(end) = self.last_date() else {
+            return self;
+        };
+        let start = self.nth_date(self.front);
+        let mut range = Self {
+            back: (decade_index(&end) - decade_index(&start)) / decades + 1,
+            start,
+            step: Step::Decades(decades),
+            front: 0,
+        };
+        if range.last_date().is_some_and(|last| last > end) {
+            range.back -= 1;
+        }
+        range
+    }
+
+    fn nth_date(&self, index: i64) -> Date {
+        match self.step {
+            Step::Days(days) => self.start.clone() + Duration::from_days(index * days),
+            Step::Decades(decades) => (self.start)
+                .try_add_decades(index * decades, Overflow::Clamp)
+                .expect("date out of range"),
+        }
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.nth_date(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).max(0) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Date> {
+        self.front = self.front.saturating_add_unsigned(n as u64).min(self.back);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Date> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.nth_date(self.back))
+    }
+}
+
+impl ExactSizeIterator for DateRange {}
+
+impl FusedIterator for DateRange {}
+
+impl Date {
+    /// Iterates over the days from this date, until they cannot be represented by a [`Timestamp`].
+    pub fn iter_days(&self) -> impl Iterator<Item = Date> + use<> {
+        std::iter::successors(Some(self.clone()), |date| {
+            date.checked_add(Duration::from_days(1))
+        })
+    }
+
+    /// Returns the days of the decade containing this date, or all the Sansculotides.
+    pub fn days_of_decade(&self) -> DateRange {
+        match self.month() {
+            Month::Sansculotides => self.days_of_month(),
+            _ => {
+                let first = self.day0() - self.num_decade_day0();
+                DateRange::new(
+                    Date::from_ymd0(self.year0(), self.num_month0(), first),
+                    Date::from_ymd0(self.year0(), self.num_month0(), first + DAYS_PER_DECADE - 1),
+                )
+            }
+        }
+    }
+
+    /// Returns the days of the month containing this date, 5 or 6 for the Sansculotides.
+    pub fn days_of_month(&self) -> DateRange {
+        let days = match self.month() {
+            Month::Sansculotides => get_day_count0(self.year0(), &Imcce) - 12 * DAYS_PER_MONTH,
+            _ => DAYS_PER_MONTH,
+        };
+        DateRange::new(
+            Date::from_ymd0(self.year0(), self.num_month0(), 0),
+            Date::from_ymd0(self.year0(), self.num_month0(), days - 1),
+        )
+    }
+
+    /// Returns the 365 or 366 days of a year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year is 0 or cannot be represented by a [`Timestamp`].
+    pub fn days_of_year(republican_year: i64) -> DateRange {
+        let start = Date::from_ymd(republican_year, 1, 1);
+        let days = get_day_count0(start.year0(), &Imcce);
+        DateRange::new(start.clone(), start + Duration::from_days(days - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let range = DateRange::new(Date::from_ymd(1, 12, 29), Date::from_ymd(2, 1, 2));
+        assert_eq!(range.len(), 9);
+        assert_eq!(range.clone().nth(2), Some(Date::from_ymd(1, 13, 1)));
+        assert_eq!(range.clone().next_back(), Some(Date::from_ymd(2, 1, 2)));
+        assert_eq!(range.clone().rev().nth(2), Some(Date::from_ymd(1, 13, 5)));
+        let mut stepped = range.step_days(3);
+        assert_eq!(stepped.len(), 3);
+        assert_eq!(stepped.next(), Some(Date::from_ymd(1, 12, 29)));
+        assert_eq!(stepped.next_back(), Some(Date::from_ymd(1, 13, 5)));
+        assert_eq!(stepped.len(), 1);
+        assert_eq!(
+            DateRange::new(Date::from_ymd(2, 1, 1), Date::from_ymd(1, 1, 1)).len(),
+            0
+        );
+
+        let decades = DateRange::new(Date::from_ymd(1, 12, 27), Date::from_ymd(2, 1, 15));
+        let decades = decades.step_decades(1).collect::<Vec<_>>();
+        assert_eq!(
+            decades,
+            [
+                Date::from_ymd(1, 12, 27),
+                Date::from_ymd(1, 13, 5),
+                Date::from_ymd(2, 1, 7),
+            ]
+        );
+        let decades = DateRange::new(Date::from_ymd(1, 12, 27), Date::from_ymd(2, 1, 6));
+        assert_eq!(decades.step_decades(1).rev().count(), 2);
+
+        let date = Date::from_ymd(3, 13, 2);
+        assert_eq!(date.days_of_month().len(), 6);
+        assert_eq!(date.days_of_decade().len(), 6);
+        assert_eq!(Date::from_ymd(4, 13, 2).days_of_month().len(), 5);
+        let mut decade = Date::from_ymd(233, 2, 14).days_of_decade();
+        assert_eq!(decade.next(), Some(Date::from_ymd(233, 2, 11)));
+        assert_eq!(decade.next_back(), Some(Date::from_ymd(233, 2, 20)));
+        assert_eq!(Date::days_of_year(3).len(), 366);
+        assert_eq!(Date::days_of_year(-1).len(), get_day_count(-1) as usize);
+        assert!(
+            Date::days_of_year(4)
+                .zip(Date::from_ymd(4, 1, 1).iter_days())
+                .all(|(a, b)| a == b)
+        );
+        assert_eq!(
+            Date::from_ymd(1, 13, 5).iter_days().nth(1),
+            Some(Date::from_ymd(2, 1, 1))
+        );
+    }
+}
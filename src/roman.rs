@@ -0,0 +1,177 @@
+//! Roman numerals, used by the traditional notation of years (`an CCXXXIII`).
+//!
+//! Numbers from 4000 are written with a vinculum: a combining overline (U+0305) after a numeral multiplies it by 1000,
+//! and numerals can have several overlines (`I̅V̅` is 4000, `I̅̅V̅̅` is 4 million).
+//! Years before the Republic are followed by an era marker (`an V avant la République` is the year -5).
+
+use crate::text::strip_prefix_folded;
+
+/// The combining overline of the vinculum.
+pub const VINCULUM: char = '\u{0305}';
+
+/// Marks years before the Republic.
+pub const ERA_MARKER: &str = "avant la République";
+
+const NUMERALS: [(u64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+fn write_numerals(s: &mut String, mut n: u64, overlines: usize) {
+    if n >= 4000 {
+        write_numerals(s, n / 1000, overlines + 1);
+        n %= 1000;
+    }
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            for c in numeral.chars() {
+                s.push(c);
+                s.extend(std::iter::repeat_n(VINCULUM, overlines));
+            }
+            n -= value;
+        }
+    }
+}
+
+/// Writes a number in roman numerals, with a vinculum from 4000.
+///
+/// Returns an empty string for 0, which has no roman numeral.
+pub fn to_roman(n: u64) -> String {
+    let mut s = String::new();
+    write_numerals(&mut s, n, 0);
+    s
+}
+
+fn numeral_value(c: char) -> Option<u64> {
+    match c.to_ascii_uppercase() {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
+
+/// Parses roman numerals, case-insensitively and with an optional vinculum.
+///
+/// Only the numerals written by [`to_roman`] are accepted, so `IIX`, `IIII` or `VV` are rejected.
+/// Returns `None` if the string contains anything else, or if the number overflows.
+pub fn from_roman(s: &str) -> Option<u64> {
+    let mut values: Vec<u128> = Vec::new();
+    for c in s.chars() {
+        if c == VINCULUM {
+            let value = values.last_mut()?;
+            *value = value.checked_mul(1000)?;
+        } else {
+            values.push(numeral_value(c)? as u128);
+        }
+    }
+    let mut total = 0u128;
+    let mut subtracted = 0u128;
+    for (i, value) in values.iter().enumerate() {
+        match values.get(i + 1) {
+            Some(next) if next > value => subtracted = subtracted.checked_add(*value)?,
+            _ => total = total.checked_add(*value)?,
+        }
+    }
+    let total = u64::try_from(total.checked_sub(subtracted)?).ok()?;
+    (total > 0 && to_roman(total) == s.to_uppercase()).then_some(total)
+}
+
+/// Writes a year in roman numerals, followed by the [`ERA_MARKER`] if it is before the Republic.
+pub fn to_roman_year(republican_year: i64) -> String {
+    to_roman_year_with(republican_year, ERA_MARKER)
+}
+
+/// Same as [`to_roman_year`] with the era marker of a [`Locale`](crate::Locale).
+pub(crate) fn to_roman_year_with(republican_year: i64, era_marker: &str) -> String {
+    let mut s = to_roman(republican_year.unsigned_abs());
+    if republican_year < 0 {
+        s.push(' ');
+        s.push_str(era_marker);
+    }
+    s
+}
+
+/// Returns the length of the roman numerals at the start of the string.
+fn numerals_len(s: &str) -> usize {
+    s.char_indices()
+        .find(|(_, c)| *c != VINCULUM && numeral_value(*c).is_none())
+        .map_or(s.len(), |(i, _)| i)
+}
+
+/// Parses a year at the start of the string, written by [`to_roman_year`], and returns the rest.
+pub(crate) fn parse_roman_year(s: &str) -> Option<(i64, &str)> {
+    let len = numerals_len(s);
+    let year = i64::try_from(from_roman(&s[..len])?).ok()?;
+    let rest = &s[len..];
+    match strip_prefix_folded(rest.trim_start(), ERA_MARKER) {
+        Some(after) if rest.starts_with(char::is_whitespace) => Some((-year, after)),
+        _ => Some((year, rest)),
+    }
+}
+
+/// Parses a year written by [`to_roman_year`], such as `CCXXXIII` or `V avant la République`.
+///
+/// Case and accents are ignored.
+pub fn from_roman_year(s: &str) -> Option<i64> {
+    match parse_roman_year(s.trim())? {
+        (year, "") => Some(year),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roman() {
+        for (n, roman) in [
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (233, "CCXXXIII"),
+            (1999, "MCMXCIX"),
+            (3999, "MMMCMXCIX"),
+            (4000, "I\u{305}V\u{305}"),
+            (4001, "I\u{305}V\u{305}I"),
+            (1_000_000, "M\u{305}"),
+            (4_000_000, "I\u{305}\u{305}V\u{305}\u{305}"),
+        ] {
+            assert_eq!(to_roman(n), roman);
+            assert_eq!(from_roman(roman), Some(n));
+        }
+        assert_eq!(to_roman(0), "");
+        assert_eq!(from_roman(&to_roman(u64::MAX)), Some(u64::MAX));
+        assert_eq!(from_roman("ccxxxiii"), Some(233));
+        assert_eq!(from_roman(""), None);
+        assert_eq!(from_roman("\u{305}I"), None);
+        assert_eq!(from_roman("I2"), None);
+        for invalid in ["IIX", "IIII", "VV", "IM", "MMMM", "I\u{305}V\u{305}\u{305}"] {
+            assert_eq!(from_roman(invalid), None);
+        }
+
+        assert_eq!(to_roman_year(-5), "V avant la République");
+        assert_eq!(from_roman_year("v avant la republique"), Some(-5));
+        assert_eq!(from_roman_year("CCXXXIII"), Some(233));
+        assert_eq!(from_roman_year("CCXXXIII après"), None);
+        for year in [1, -1, 233, -233, 4000, i64::MAX, i64::MIN + 1] {
+            assert_eq!(from_roman_year(&to_roman_year(year)), Some(year));
+        }
+    }
+}
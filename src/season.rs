@@ -0,0 +1,120 @@
+use crate::text::fold;
+use crate::{Error, Locale, Localized, Month};
+
+/// A season of three months, as grouped by article IX of the decree.
+///
+/// The Sansculotides are outside any season.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Season {
+    Automne,
+    Hiver,
+    Printemps,
+    Été,
+}
+
+impl Season {
+    /// All the seasons, in order.
+    pub const ALL: [Self; 4] = [Self::Automne, Self::Hiver, Self::Printemps, Self::Été];
+
+    /// Iterates over all the values, in order.
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the next value, wrapping around after the last one.
+    pub fn succ(&self) -> Self {
+        Self::ALL[(self.num0() as usize + 1) % Self::ALL.len()]
+    }
+
+    /// Returns the previous value, wrapping around before the first one.
+    pub fn pred(&self) -> Self {
+        Self::ALL[(self.num0() as usize + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Automne => "Automne",
+            Self::Hiver => "Hiver",
+            Self::Printemps => "Printemps",
+            Self::Été => "Été",
+        }
+    }
+
+    /// Returns a [`Display`](std::fmt::Display) implementation with the name in a [`Locale`].
+    pub fn localized<'a>(&'a self, locale: &'a dyn Locale) -> Localized<'a, Self> {
+        Localized::new(self, locale)
+    }
+
+    pub const fn num0(&self) -> i64 {
+        *self as i64
+    }
+
+    pub const fn num(&self) -> i64 {
+        self.num0() + 1
+    }
+
+    /// Returns the three months of the season, in order.
+    pub fn months(&self) -> [Month; 3] {
+        let first = self.num0() * 3;
+        [first, first + 1, first + 2].map(Month::from_num0)
+    }
+
+    /// Returns the suffix shared by the names of the months of the season.
+    pub const fn suffix(&self) -> &'static str {
+        match self {
+            Self::Automne => "aire",
+            Self::Hiver => "ôse",
+            Self::Printemps => "al",
+            Self::Été => "idor",
+        }
+    }
+}
+
+impl std::fmt::Display for Season {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the French name, regardless of case and accents.
+impl std::str::FromStr for Season {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = fold(s.trim());
+        Self::iter()
+            .find(|value| fold(value.name()) == name)
+            .ok_or(Error::InvalidFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_season() {
+        assert_eq!(Month::Vendémiaire.season(), Some(Season::Automne));
+        assert_eq!(Month::Nivôse.season(), Some(Season::Hiver));
+        assert_eq!(Month::Floréal.season(), Some(Season::Printemps));
+        assert_eq!(Month::Fructidor.season(), Some(Season::Été));
+        assert_eq!(Month::Sansculotides.season(), None);
+        for season in Season::iter() {
+            for month in season.months() {
+                assert_eq!(month.season(), Some(season));
+                assert!(month.name().ends_with(season.suffix()));
+            }
+        }
+        assert_eq!(Season::Été.succ(), Season::Automne);
+        assert_eq!("ete".parse(), Ok(Season::Été));
+
+        assert_eq!(Date::from_ymd(233, 2, 11).season(), Some(Season::Automne));
+        assert_eq!(Date::from_ymd(3, 13, 6).season(), None);
+        assert_eq!(DateTime::from_ymd(233, 10, 1).season(), Some(Season::Été));
+
+        assert_eq!(Season::Hiver.localized(&English).to_string(), "Winter");
+        assert_eq!(Season::Printemps.localized(&French).to_string(), "Printemps");
+        assert_eq!(Season::Automne.localized(&German).to_string(), "Herbst");
+    }
+}
@@ -0,0 +1,260 @@
+//! Serialization with [serde](https://serde.rs), enabled by the `serde` feature.
+//!
+//! - [`Timestamp`] is its exact number of republican nanoseconds, see [`Timestamp::as_nanos`].
+//!   The [`ts_struct`] module represents it as a struct instead, for formats that don't support 128-bit integers.
+//! - [`Date`] and [`DateTime`] are strings in their [`Display`](std::fmt::Display) form, such as `Primidi 1 Vendémiaire 1 0:00:00`.
+//!   The [`date_struct`] and [`datetime_struct`] modules represent them as structs of numbers instead.
+//! - [`Month`], [`Day`], [`RegularDay`], [`SansculottideDay`] and [`Season`] are their French names.
+//!   Names are deserialized regardless of case and accents.
+//!
+//! ```
+//! use calendrier::{Date, Timestamp};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "calendrier::serde::date_struct")]
+//!     date: Date,
+//!     timestamp: Timestamp,
+//! }
+//!
+//! let event = Event {
+//!     date: Date::from_ymd(233, 2, 11),
+//!     timestamp: Timestamp::from_seconds(42),
+//! };
+//! assert_eq!(
+//!     serde_json::to_string(&event).unwrap(),
+//!     r#"{"date":{"year":233,"month":2,"day":11},"timestamp":42000000000}"#
+//! );
+//! ```
+
+use crate::*;
+use ::serde::de::{self, Deserialize, Deserializer};
+use ::serde::ser::{Serialize, Serializer};
+use std::str::FromStr;
+
+fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = Error>,
+{
+    let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+    s.parse().map_err(de::Error::custom)
+}
+
+macro_rules! impl_as_str {
+    ($($type:ty),*) => {
+        $(
+            impl Serialize for $type {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $type {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_from_str(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_as_str!(Date, DateTime, Month, Day, RegularDay, SansculottideDay, Season);
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i128(self.as_nanos())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let nanos = i128::deserialize(deserializer)?;
+        Timestamp::checked_from_nanos(nanos).ok_or(de::Error::custom(Error::TimestampOutOfRange))
+    }
+}
+
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(rename = "Timestamp")]
+struct TimestampFields {
+    seconds: i64,
+    #[serde(default)]
+    nanos: u32,
+}
+
+/// Represents a [`Timestamp`] as a struct with its `seconds` and `nanos`, for `#[serde(with = "calendrier::serde::ts_struct")]`.
+///
+/// The `nanos` are optional when deserializing.
+pub mod ts_struct {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        TimestampFields {
+            seconds: timestamp.seconds,
+            nanos: timestamp.nanos,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let TimestampFields { seconds, nanos } = TimestampFields::deserialize(deserializer)?;
+        if nanos as i128 >= NANOS_PER_SECOND {
+            return Err(de::Error::custom(Error::NanosecondOutOfRange));
+        }
+        Ok(Timestamp { seconds, nanos })
+    }
+}
+
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+struct DateFields {
+    year: i64,
+    month: i64,
+    day: i64,
+}
+
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+struct DateTimeFields {
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: i64,
+    minute: i64,
+    second: i64,
+    #[serde(default)]
+    nanosecond: i64,
+}
+
+/// Represents a [`Date`] as a struct with a `year`, a `month` and a `day`, for `#[serde(with = "calendrier::serde::date_struct")]`.
+///
+/// Months and days start from 1.
+pub mod date_struct {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        DateFields {
+            year: date.year(),
+            month: date.num_month(),
+            day: date.day(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        let DateFields { year, month, day } = DateFields::deserialize(deserializer)?;
+        Date::try_from_ymd(year, month, day).map_err(de::Error::custom)
+    }
+}
+
+/// Represents a [`DateTime`] as a struct with the fields of [`date_struct`], an `hour`, a `minute`, a `second` and a `nanosecond`,
+/// for `#[serde(with = "calendrier::serde::datetime_struct")]`.
+///
+/// The `nanosecond` is optional when deserializing.
+pub mod datetime_struct {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(datetime: &DateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        DateTimeFields {
+            year: datetime.year(),
+            month: datetime.num_month(),
+            day: datetime.day(),
+            hour: datetime.hour(),
+            minute: datetime.minute(),
+            second: datetime.second(),
+            nanosecond: datetime.nanosecond(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+        let fields = DateTimeFields::deserialize(deserializer)?;
+        DateTime::try_from_ymd_hms(
+            fields.year,
+            fields.month,
+            fields.day,
+            fields.hour,
+            fields.minute,
+            fields.second,
+        )
+        .and_then(|datetime| datetime.try_with_nanosecond(fields.nanosecond))
+        .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde::de::DeserializeOwned;
+
+    fn round_trip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(value: T, json: &str) {
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+    }
+
+    #[test]
+    fn test_serde() {
+        round_trip(Timestamp::from_seconds(-42), "-42000000000");
+        round_trip(Timestamp::from_nanos(1_500_000_000), "1500000000");
+        let timestamp = Timestamp::from_unix_nanos(1_700_000_000_123_456_789);
+        round_trip(timestamp, &timestamp.as_nanos().to_string());
+        assert!(serde_json::from_str::<Timestamp>("1.5").is_err());
+        round_trip(Month::Nivôse, "\"Nivôse\"");
+        round_trip(RegularDay::Primedi, "\"Primidi\"");
+        round_trip(Day::Sansculottide(SansculottideDay::Génie), "\"Jour du génie\"");
+        round_trip(Season::Été, "\"Été\"");
+        round_trip(Date::from_ymd(233, 2, 11), "\"Primidi 11 Brumaire 233\"");
+        round_trip(
+            DateTime::from_ymd_hms(-5, 1, 1, 3, 7, 4),
+            "\"Primidi 1 Vendémiaire -5 3:07:04\"",
+        );
+        assert_eq!(serde_json::from_str::<Month>("\"nivose\"").unwrap(), Month::Nivôse);
+        assert!(serde_json::from_str::<Month>("\"Janvier\"").is_err());
+        assert!(serde_json::from_str::<Date>("\"Primidi 31 Brumaire 233\"").is_err());
+
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Event {
+            #[serde(with = "date_struct")]
+            date: Date,
+            #[serde(with = "datetime_struct")]
+            datetime: DateTime,
+            #[serde(with = "ts_struct")]
+            timestamp: Timestamp,
+        }
+        let datetime = "11 Brumaire 233 1:02:03.5".parse::<DateTime>().unwrap();
+        let timestamp = datetime.timestamp();
+        round_trip(
+            Event {
+                date: Date::from_ymd(233, 2, 11),
+                datetime,
+                timestamp,
+            },
+            &format!(
+                concat!(
+                    r#"{{"date":{{"year":233,"month":2,"day":11}},"#,
+                    r#""datetime":{{"year":233,"month":2,"day":11,"hour":1,"minute":2,"second":3,"nanosecond":500000000}},"#,
+                    r#""timestamp":{{"seconds":{},"nanos":{}}}}}"#
+                ),
+                timestamp.seconds,
+                timestamp.nanos
+            ),
+        );
+        let event = r#"{"date":{"year":1,"month":13,"day":6},"datetime":{"year":1,"month":1,"day":1,"hour":0,"minute":0,"second":0},"timestamp":{"seconds":0}}"#;
+        let error = serde_json::from_str::<Event>(event).unwrap_err().to_string();
+        assert!(error.starts_with("sansculottide day is beyond the end of the year"));
+        let event = event
+            .replace(r#""day":6"#, r#""day":5"#)
+            .replace(r#""second":0"#, r#""second":0,"nanosecond":-1"#);
+        let error = serde_json::from_str::<Event>(&event).unwrap_err().to_string();
+        assert!(error.starts_with("nanosecond must be in [0, 999999999]"));
+        let event = event
+            .replace(r#","nanosecond":-1"#, "")
+            .replace(r#"{"seconds":0}"#, r#"{"seconds":0,"nanos":1000000000}"#);
+        let error = serde_json::from_str::<Event>(&event).unwrap_err().to_string();
+        assert!(error.starts_with("nanosecond must be in [0, 999999999]"));
+        let event = event.replace(r#","nanos":1000000000"#, "");
+        assert_eq!(
+            serde_json::from_str::<Event>(&event).unwrap().timestamp,
+            Timestamp::from_seconds(0)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Case- and accent-insensitive matching of names.
+
+fn is_combining_accent(c: char) -> bool {
+    ('\u{0300}'..='\u{036f}').contains(&c)
+}
+
+/// Pushes the lowercase, unaccented form of a character.
+fn push_folded(s: &mut String, c: char) {
+    for c in c.to_lowercase() {
+        match c {
+            'à' | 'â' | 'ä' => s.push('a'),
+            'é' | 'è' | 'ê' | 'ë' => s.push('e'),
+            'î' | 'ï' => s.push('i'),
+            'ô' | 'ö' => s.push('o'),
+            'ù' | 'û' | 'ü' => s.push('u'),
+            'ÿ' => s.push('y'),
+            'ç' => s.push('c'),
+            'œ' => s.push_str("oe"),
+            'æ' => s.push_str("ae"),
+            '’' | 'ʼ' => s.push('\''),
+            c if is_combining_accent(c) => (),
+            c => s.push(c),
+        }
+    }
+}
+
+/// Returns the lowercase, unaccented form of a string.
+pub(crate) fn fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars() {
+        push_folded(&mut folded, c);
+    }
+    folded
+}
+
+/// Returns the rest of `input` if it starts with `prefix`, ignoring case and accents.
+pub(crate) fn strip_prefix_folded<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let prefix = fold(prefix);
+    let mut folded = String::new();
+    for (i, c) in input.char_indices() {
+        if folded.len() >= prefix.len() && !is_combining_accent(c) {
+            return (folded == prefix).then(|| &input[i..]);
+        }
+        push_folded(&mut folded, c);
+        if !prefix.starts_with(&folded) {
+            return None;
+        }
+    }
+    (folded == prefix).then_some("")
+}
+
+/// Returns the rest of `input` after the longest name it starts with, along with the name.
+pub(crate) fn strip_longest_prefix<T>(
+    input: &str,
+    names: impl IntoIterator<Item = (T, &'static str)>,
+) -> Option<(T, &str)> {
+    names
+        .into_iter()
+        .filter_map(|(value, name)| Some((value, strip_prefix_folded(input, name)?)))
+        .min_by_key(|(_, rest)| rest.len())
+}
+
+/// Returns the number of characters to insert, delete or substitute to turn `a` into `b`.
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[test]
+fn test_fold() {
+    assert_eq!(fold("Vendémiaire"), "vendemiaire");
+    assert_eq!(fold("Nivôse"), "nivose");
+    assert_eq!(fold("Jour de l’Opinion"), "jour de l'opinion");
+    assert_eq!(
+        strip_prefix_folded("VENDEMIAIRE 1", "Vendémiaire"),
+        Some(" 1")
+    );
+    assert_eq!(
+        strip_prefix_folded("Vende\u{301}miaire", "Vendémiaire"),
+        Some("")
+    );
+    assert_eq!(strip_prefix_folded("Vend", "Vendémiaire"), None);
+    assert_eq!(
+        strip_longest_prefix("Jour de la Vertu", [(1, "Jour"), (2, "Jour de la vertu")]),
+        Some((2, ""))
+    );
+    assert_eq!(levenshtein("cheval", "chevl"), 1);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+}
@@ -1,3 +1,5 @@
+use crate::Offset;
+
 /// Sources:
 /// https://www.imcce.fr/newsletter/docs/Equinoxe_automne_1583_2999.pdf
 /// https://fr.wikisource.org/wiki/D%C3%A9cret_de_la_Convention_nationale_portant_sur_la_cr%C3%A9ation_du_calendrier_r%C3%A9publicain
@@ -10,14 +12,9 @@
 /// 14:55:19 today = 15:11:38 then
 /// Meaning there is a 979 gregorian seconds offset between the times.
 ///
-/// The first offset was chosen for this library.
+/// The first offset was chosen for this library by default.
+/// Another one can be picked at runtime with [`Offset`].
 pub(crate) const REPUBLICAN_EPOCH_GREGORIAN_SECONDS: i64 = -5594227200;
-#[cfg(not(any(feature = "no-time-offset", feature = "average-time-offset")))]
-pub(crate) const OFFSET_GREGORIAN_SECONDS: i64 = 1080;
-#[cfg(feature = "no-time-offset")]
-pub(crate) const OFFSET_GREGORIAN_SECONDS: i64 = 0;
-#[cfg(feature = "average-time-offset")]
-pub(crate) const OFFSET_GREGORIAN_SECONDS: i64 = 1029;
 pub(crate) const REPUBLICAN_SECONDS_PER_DAY: i64 = 100000;
 pub(crate) const GREGORIAN_SECONDS_PER_DAY: i64 = 86400;
 
@@ -26,32 +23,160 @@ compile_error!(
     "You cannot enable both no-time-offset and average-time-offset features at the same time"
 );
 
+pub(crate) const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
 /// It is necessary to have a different timestamp than UNIX systems as seconds are different in the Republican Calendar.
 /// Indeed, there are 86400 seconds in a day in the Gregorian Calendar, but 100000 seconds in a day in the Republican Calendar.
+///
+/// Timestamps have nanosecond precision.
+/// Converting a unix time to a [`Timestamp`] and back is guaranteed to be exact, at any precision down to the nanosecond.
+/// The reverse is not true: a republican nanosecond is shorter than a gregorian one, so distinct timestamps may share the same unix nanosecond.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Timestamp {
     /// The number of seconds since the beginning of the Republican Calendar.
     pub seconds: i64,
+    /// The number of nanoseconds elapsed since the beginning of the current second, in [0, 999_999_999].
+    pub nanos: u32,
 }
 
 impl Timestamp {
-    pub fn from_unix(unix_timestamp: i64) -> Self {
-        let gregorian_seconds =
-            unix_timestamp - REPUBLICAN_EPOCH_GREGORIAN_SECONDS + OFFSET_GREGORIAN_SECONDS;
-        let republican_seconds =
-            gregorian_seconds * REPUBLICAN_SECONDS_PER_DAY / GREGORIAN_SECONDS_PER_DAY;
-        Self {
-            seconds: republican_seconds,
+    /// Creates a timestamp from a whole number of republican seconds.
+    pub const fn from_seconds(seconds: i64) -> Self {
+        Self { seconds, nanos: 0 }
+    }
+
+    /// Creates a timestamp from a number of republican nanoseconds since the republican epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of seconds does not fit in an `i64`.
+    pub const fn from_nanos(nanos: i128) -> Self {
+        match Self::checked_from_nanos(nanos) {
+            Some(ts) => ts,
+            None => panic!("timestamp out of range"),
         }
     }
 
+    /// Creates a timestamp from a number of republican nanoseconds since the republican epoch.
+    /// Returns `None` if the number of seconds does not fit in an `i64`.
+    pub const fn checked_from_nanos(nanos: i128) -> Option<Self> {
+        let seconds = nanos.div_euclid(NANOS_PER_SECOND);
+        if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+            return None;
+        }
+        Some(Self {
+            seconds: seconds as i64,
+            nanos: nanos.rem_euclid(NANOS_PER_SECOND) as u32,
+        })
+    }
+
+    /// Returns the number of republican nanoseconds since the republican epoch.
+    pub const fn as_nanos(&self) -> i128 {
+        self.seconds as i128 * NANOS_PER_SECOND + self.nanos as i128
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the result cannot be represented, see [`Timestamp::checked_from_unix`].
+    pub fn from_unix(unix_timestamp: i64) -> Self {
+        Self::from_unix_with(unix_timestamp, Offset::DEFAULT)
+    }
+
+    /// Same as [`Timestamp::from_unix`] with an explicit [`Offset`].
+    pub fn from_unix_with(unix_timestamp: i64, offset: Offset) -> Self {
+        Self::checked_from_unix_with(unix_timestamp, offset).expect("timestamp out of range")
+    }
+
+    /// Returns `None` if the republican timestamp would not fit in an `i64` number of seconds.
+    /// This can only happen for unix timestamps beyond `±7.9e18`.
+    pub fn checked_from_unix(unix_timestamp: i64) -> Option<Self> {
+        Self::checked_from_unix_with(unix_timestamp, Offset::DEFAULT)
+    }
+
+    /// Same as [`Timestamp::checked_from_unix`] with an explicit [`Offset`].
+    pub fn checked_from_unix_with(unix_timestamp: i64, offset: Offset) -> Option<Self> {
+        Self::checked_from_unix_nanos_with(unix_timestamp as i128 * NANOS_PER_SECOND, offset)
+    }
+
+    /// Rounds down to the unix second.
     pub fn to_unix(&self) -> i64 {
-        let gregorian_seconds =
-            self.seconds * GREGORIAN_SECONDS_PER_DAY / REPUBLICAN_SECONDS_PER_DAY;
-        gregorian_seconds + REPUBLICAN_EPOCH_GREGORIAN_SECONDS - OFFSET_GREGORIAN_SECONDS
+        self.to_unix_with(Offset::DEFAULT)
+    }
+
+    /// Same as [`Timestamp::to_unix`] with an explicit [`Offset`].
+    pub fn to_unix_with(&self, offset: Offset) -> i64 {
+        self.checked_to_unix_with(offset)
+            .expect("unix timestamp out of range")
+    }
+
+    /// Rounds down to the unix second.
+    ///
+    /// Republican seconds being shorter than gregorian ones, every [`Timestamp`] has a unix equivalent, so this never returns `None` in practice.
+    pub fn checked_to_unix(&self) -> Option<i64> {
+        self.checked_to_unix_with(Offset::DEFAULT)
+    }
+
+    /// Same as [`Timestamp::checked_to_unix`] with an explicit [`Offset`].
+    /// Returns `None` if the result doesn't fit in an `i64`, which takes an [`Offset::Custom`] of more than 10¹⁸ seconds.
+    pub fn checked_to_unix_with(&self, offset: Offset) -> Option<i64> {
+        i64::try_from(self.to_unix_nanos_with(offset).div_euclid(NANOS_PER_SECOND)).ok()
+    }
+
+    /// Creates a timestamp from a number of nanoseconds since the unix epoch.
+    ///
+    /// The result is rounded down to the republican nanosecond.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result cannot be represented, see [`Timestamp::checked_from_unix_nanos`].
+    pub fn from_unix_nanos(unix_nanos: i128) -> Self {
+        Self::from_unix_nanos_with(unix_nanos, Offset::DEFAULT)
+    }
+
+    /// Same as [`Timestamp::from_unix_nanos`] with an explicit [`Offset`].
+    pub fn from_unix_nanos_with(unix_nanos: i128, offset: Offset) -> Self {
+        Self::checked_from_unix_nanos_with(unix_nanos, offset).expect("timestamp out of range")
+    }
+
+    /// Creates a timestamp from a number of nanoseconds since the unix epoch.
+    /// Returns `None` if the number of republican seconds would not fit in an `i64`.
+    ///
+    /// The result is rounded down to the republican nanosecond.
+    pub fn checked_from_unix_nanos(unix_nanos: i128) -> Option<Self> {
+        Self::checked_from_unix_nanos_with(unix_nanos, Offset::DEFAULT)
+    }
+
+    /// Same as [`Timestamp::checked_from_unix_nanos`] with an explicit [`Offset`].
+    pub fn checked_from_unix_nanos_with(unix_nanos: i128, offset: Offset) -> Option<Self> {
+        let gregorian_nanos = unix_nanos.checked_sub(epoch_unix_nanos(offset))?;
+        let republican_nanos = gregorian_nanos
+            .checked_mul(REPUBLICAN_SECONDS_PER_DAY as i128)?
+            .div_euclid(GREGORIAN_SECONDS_PER_DAY as i128);
+        Self::checked_from_nanos(republican_nanos)
+    }
+
+    /// Returns the number of nanoseconds since the unix epoch.
+    ///
+    /// The result is rounded up to the gregorian nanosecond, which makes `Timestamp::from_unix_nanos(n).to_unix_nanos() == n` hold for any `n`.
+    /// This conversion cannot overflow, whatever the [`Offset`].
+    pub fn to_unix_nanos(&self) -> i128 {
+        self.to_unix_nanos_with(Offset::DEFAULT)
+    }
+
+    /// Same as [`Timestamp::to_unix_nanos`] with an explicit [`Offset`].
+    pub fn to_unix_nanos_with(&self, offset: Offset) -> i128 {
+        let gregorian_nanos = -(-self.as_nanos() * GREGORIAN_SECONDS_PER_DAY as i128)
+            .div_euclid(REPUBLICAN_SECONDS_PER_DAY as i128);
+        gregorian_nanos + epoch_unix_nanos(offset)
     }
 }
 
+/// Returns the unix time of the republican epoch in nanoseconds, computed in `i128` so that no offset can overflow.
+fn epoch_unix_nanos(offset: Offset) -> i128 {
+    (REPUBLICAN_EPOCH_GREGORIAN_SECONDS as i128 - offset.gregorian_seconds() as i128)
+        * NANOS_PER_SECOND
+}
+
 #[cfg(test)]
 mod tests {
     use crate::DateTime;
@@ -85,6 +210,80 @@ mod tests {
         assert_eq!(ts3, ts4);
     }
 
+    #[test]
+    fn test_unix_round_trip() {
+        for unix_nanos in [
+            0,
+            1,
+            -1,
+            999_999_999,
+            1_700_000_000_123_000_000,
+            -5_594_228_280_000_000_001,
+            -1_000_000_000_000_000_000_007,
+        ] {
+            let ts = Timestamp::from_unix_nanos(unix_nanos);
+            assert!(ts.nanos < 1_000_000_000);
+            assert_eq!(ts.to_unix_nanos(), unix_nanos);
+        }
+        for unix in (-10_000_000_000..10_000_000_000).step_by(999_999_937) {
+            assert_eq!(Timestamp::from_unix(unix).to_unix(), unix);
+        }
+
+        let epoch = Timestamp::from_seconds(0).to_unix_nanos();
+        assert_eq!(Timestamp::from_unix_nanos(epoch + 864_000_000), Timestamp::from_seconds(1));
+        assert_eq!(
+            Timestamp::from_unix_nanos(epoch + 432_000_000),
+            Timestamp { seconds: 0, nanos: 500_000_000 }
+        );
+    }
+
+    #[test]
+    fn test_checked() {
+        assert!(Timestamp::checked_from_unix(i64::MAX).is_none());
+        assert!(Timestamp::checked_from_unix(i64::MIN).is_none());
+        assert!(Timestamp::checked_from_unix_nanos(i128::MAX).is_none());
+        assert!(Timestamp::checked_from_unix_nanos(i128::MIN).is_none());
+        assert!(Timestamp::checked_from_nanos(i128::MAX).is_none());
+        assert!(Timestamp::checked_from_unix(7_000_000_000_000_000_000).is_some());
+
+        for seconds in [i64::MIN + 2, i64::MAX] {
+            let ts = Timestamp::from_seconds(seconds);
+            let unix = ts.checked_to_unix().unwrap();
+            assert_eq!(Timestamp::checked_from_unix(unix).unwrap().to_unix(), unix);
+        }
+    }
+
+    #[test]
+    fn test_runtime_offset() {
+        let midnight = chrono::Utc
+            .with_ymd_and_hms(1792, 9, 22, 0, 0, 0)
+            .single()
+            .unwrap()
+            .timestamp();
+        assert_eq!(Timestamp::from_unix_with(midnight, Offset::ArticleOne).seconds, 1250);
+        assert_eq!(Timestamp::from_unix_with(midnight, Offset::Average).seconds, 1190);
+        assert_eq!(Timestamp::from_unix_with(midnight, Offset::Zero).seconds, 0);
+        assert_eq!(Timestamp::from_unix_with(midnight, Offset::Custom(-864)).seconds, -1000);
+        assert_eq!(Timestamp::from_unix(midnight), Timestamp::from_unix_with(midnight, Offset::default()));
+
+        for offset in [Offset::ArticleOne, Offset::Average, Offset::Zero, Offset::Custom(12345)] {
+            let ts = Timestamp::from_unix_with(midnight, offset);
+            assert_eq!(ts.to_unix_with(offset), midnight);
+        }
+
+        let epoch = Timestamp::from_seconds(0);
+        assert_eq!(
+            epoch.checked_to_unix_with(Offset::Custom(i64::MIN)),
+            Some(i64::MAX + REPUBLICAN_EPOCH_GREGORIAN_SECONDS + 1)
+        );
+        assert_eq!(epoch.checked_to_unix_with(Offset::Custom(i64::MAX)), None);
+        for offset in [Offset::Custom(i64::MIN), Offset::Custom(i64::MAX)] {
+            let unix_nanos = epoch.to_unix_nanos_with(offset);
+            assert_eq!(Timestamp::checked_from_unix_nanos_with(unix_nanos, offset), Some(epoch));
+            assert!(Timestamp::checked_from_unix_with(0, offset).is_none());
+        }
+    }
+
     #[test]
     fn hour_converter() {
         let hours = vec![
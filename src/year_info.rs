@@ -0,0 +1,161 @@
+use crate::*;
+
+/// Where the start of a year comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YearSource {
+    /// The equinox is listed in the table of the Observatoire de Paris, from 1583 to 2999.
+    Imcce,
+    /// The year is outside of the table.
+    /// Its start is extrapolated with a sextile year every 4 years, or computed with the `astronomical` feature.
+    Extrapolated,
+}
+
+/// A date in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GregorianDate {
+    pub year: i64,
+    /// Starting from 1.
+    pub month: i64,
+    /// Starting from 1.
+    pub day: i64,
+}
+
+impl GregorianDate {
+    /// Returns the date that is `days` days after the unix epoch (1970-01-01).
+    ///
+    /// Source: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+    pub(crate) fn from_unix_days(days: i64) -> Self {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let day_of_era = z.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        Self { year, month, day }
+    }
+}
+
+impl std::fmt::Display for GregorianDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Number of days between the unix epoch and the first day of the Republic.
+const REPUBLICAN_EPOCH_UNIX_DAYS: i64 = -64748;
+
+/// Everything there is to know about a republican year.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct YearInfo {
+    /// The year, starting from 1.
+    pub year: i64,
+    /// The midnight starting the year.
+    pub start: Timestamp,
+    /// The gregorian date of the first day of the year.
+    pub gregorian_start: GregorianDate,
+    /// 365 or 366 days.
+    pub day_count: i64,
+    /// Whether the year has a 6th Sansculottide day, the jour de la Révolution.
+    pub sextile: bool,
+    /// The autumn equinox starting the year.
+    /// Outside of the IMCCE table, it is computed with [`compute_equinox`] and might not fall on the first day of the year.
+    pub equinox: DateTime,
+    /// The franciade containing the year, starting from 1.
+    pub franciade: i64,
+    /// Where the start of the year comes from.
+    pub source: YearSource,
+}
+
+impl YearInfo {
+    /// Returns `None` if the year is 0 or cannot be represented by timestamps.
+    pub fn new(republican_year: i64) -> Option<Self> {
+        if republican_year == 0 {
+            return None;
+        }
+        let republican_year0 = republican_year0(republican_year);
+        let start = checked_get_year_start(republican_year)?;
+        checked_get_year_start0(republican_year0.checked_add(1)?)?;
+        let day_count = get_day_count(republican_year);
+
+        let source = match try_year_starts0(republican_year0) {
+            Some(_) => YearSource::Imcce,
+            None => YearSource::Extrapolated,
+        };
+        let equinox = match source {
+            YearSource::Imcce => get_equinox(republican_year),
+            YearSource::Extrapolated => compute_equinox(republican_year)?,
+        };
+
+        Some(Self {
+            year: republican_year,
+            start: Timestamp::from_seconds(start),
+            gregorian_start: GregorianDate::from_unix_days(
+                start.div_euclid(REPUBLICAN_SECONDS_PER_DAY) + REPUBLICAN_EPOCH_UNIX_DAYS,
+            ),
+            day_count,
+            sextile: day_count == 366,
+            equinox: DateTime::from_timestamp(Timestamp::from_seconds(equinox)),
+            franciade: years::republican_year(franciade0_of_year0(republican_year0)),
+            source,
+        })
+    }
+
+    /// Iterates over the years of a range, skipping the year 0 and the years that cannot be represented by timestamps.
+    pub fn iter(years: std::ops::RangeInclusive<i64>) -> impl DoubleEndedIterator<Item = YearInfo> {
+        years.filter_map(YearInfo::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_year_info() {
+        let info = YearInfo::new(1).unwrap();
+        assert_eq!(info.start, Timestamp::from_seconds(0));
+        assert_eq!(info.gregorian_start.to_string(), "1792-09-22");
+        assert_eq!(info.day_count, 365);
+        assert!(!info.sextile);
+        assert_eq!(info.equinox.hms(), (3, 87, 84)); // 09:18:30 at the Paris observatory
+        assert_eq!(info.franciade, 1);
+        assert_eq!(info.source, YearSource::Imcce);
+
+        let info = YearInfo::new(3).unwrap();
+        assert!(info.sextile);
+        assert_eq!(info.franciade, 1);
+        assert_eq!(YearInfo::new(4).unwrap().franciade, 2);
+        assert_eq!(YearInfo::new(-1).unwrap().franciade, 1);
+        assert_eq!(YearInfo::new(-2).unwrap().franciade, -1);
+
+        let info = YearInfo::new(233).unwrap();
+        assert_eq!(info.gregorian_start.to_string(), "2024-09-22");
+        assert_eq!(info.equinox.year(), 233);
+        assert_eq!(info.equinox.day(), 1);
+
+        let info = YearInfo::new(-500).unwrap();
+        assert_eq!(info.source, YearSource::Extrapolated);
+        assert_eq!(info.gregorian_start.year, 1292);
+
+        assert!(YearInfo::new(0).is_none());
+        assert!(YearInfo::new(i64::MAX).is_none());
+        let years = YearInfo::iter(-2..=2)
+            .map(|info| info.year)
+            .collect::<Vec<_>>();
+        assert_eq!(years, [-2, -1, 1, 2]);
+        assert_eq!(YearInfo::iter(1..=4).filter(|info| info.sextile).count(), 1);
+        assert_eq!(GregorianDate::from_unix_days(0).to_string(), "1970-01-01");
+        assert_eq!(
+            GregorianDate::from_unix_days(-719529),
+            GregorianDate {
+                year: -1,
+                month: 12,
+                day: 31
+            }
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::*;
+
+/// A month of a given year, such as Brumaire 233.
+///
+/// The Sansculotides are a 13th month of 5 or 6 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year0: i64,
+    month: Month,
+}
+
+impl YearMonth {
+    /// # Panics
+    ///
+    /// Panics if the year is 0 or cannot be represented by a [`Timestamp`].
+    pub fn new(year: i64, month: Month) -> Self {
+        Self::try_new(year, month).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Same as [`YearMonth::new`] but returns an error instead of panicking.
+    pub fn try_new(year: i64, month: Month) -> Result<Self, Error> {
+        let date = Date::try_from_ymd(year, month.num(), 1)?;
+        Ok(Self::from(&date))
+    }
+
+    pub fn year(&self) -> i64 {
+        republican_year(self.year0)
+    }
+
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    pub fn first_day(&self) -> Date {
+        Date::from_ymd0(self.year0, self.month.num0(), 0)
+    }
+
+    pub fn last_day(&self) -> Date {
+        Date::from_ymd0(self.year0, self.month.num0(), self.num_days() - 1)
+    }
+
+    /// Returns the days of the month, see [`Date::days_of_month`].
+    pub fn days(&self) -> DateRange {
+        DateRange::new(self.first_day(), self.last_day())
+    }
+
+    /// Returns 30, or 5 or 6 for the Sansculotides.
+    pub fn num_days(&self) -> i64 {
+        match self.month {
+            Month::Sansculotides => get_day_count0(self.year0, &Imcce) - 12 * DAYS_PER_MONTH,
+            _ => DAYS_PER_MONTH,
+        }
+    }
+
+    pub fn contains(&self, date: &Date) -> bool {
+        *self == Self::from(date)
+    }
+
+    /// Returns the following month, Vendémiaire of the next year after the Sansculotides.
+    pub fn next(&self) -> Self {
+        Self {
+            year0: self.year0 + i64::from(self.month == Month::Sansculotides),
+            month: self.month.succ(),
+        }
+    }
+
+    /// Returns the previous month, the Sansculotides of the previous year before Vendémiaire.
+    pub fn prev(&self) -> Self {
+        Self {
+            year0: self.year0 - i64::from(self.month == Month::Vendémiaire),
+            month: self.month.pred(),
+        }
+    }
+}
+
+impl From<&Date> for YearMonth {
+    fn from(date: &Date) -> Self {
+        Self {
+            year0: date.year0(),
+            month: date.month(),
+        }
+    }
+}
+
+impl From<Date> for YearMonth {
+    fn from(date: Date) -> Self {
+        Self::from(&date)
+    }
+}
+
+/// Formats as `Brumaire 233`.
+impl std::fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.month, self.year())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_year_month() {
+        let month = YearMonth::from(Date::from_ymd(233, 2, 11));
+        assert_eq!(month, YearMonth::new(233, Month::Brumaire));
+        assert_eq!(month.to_string(), "Brumaire 233");
+        assert_eq!(month.first_day(), Date::from_ymd(233, 2, 1));
+        assert_eq!(month.last_day(), Date::from_ymd(233, 2, 30));
+        assert!(month.contains(&Date::from_ymd(233, 2, 30)));
+        assert!(!month.contains(&Date::from_ymd(232, 2, 30)));
+        assert_eq!(month.days().len(), 30);
+
+        let sansculotides = YearMonth::new(3, Month::Sansculotides);
+        assert_eq!(sansculotides.num_days(), 6);
+        assert_eq!(sansculotides.next().num_days(), 30);
+        assert_eq!(sansculotides.next(), YearMonth::new(4, Month::Vendémiaire));
+        assert_eq!(sansculotides.next().next().prev().prev(), sansculotides);
+        assert_eq!(
+            YearMonth::new(1, Month::Vendémiaire).prev(),
+            YearMonth::new(-1, Month::Sansculotides)
+        );
+        assert_eq!(YearMonth::try_new(0, Month::Brumaire), Err(Error::YearZero));
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{equinoxes::TIMESTAMPS, REPUBLICAN_SECONDS_PER_DAY};
+use crate::{equinoxes::TIMESTAMPS, Imcce, YearStartProvider, REPUBLICAN_SECONDS_PER_DAY};
+#[cfg(feature = "astronomical")]
+use crate::astronomy::compute_equinox0;
 
-fn try_year_starts0(republican_year0: i64) -> Option<i64> {
-    let index = republican_year0 + 209;
+/// Returns the start of a year listed in the IMCCE table, or `None` outside of it.
+pub(crate) fn try_year_starts0(republican_year0: i64) -> Option<i64> {
+    let index = republican_year0.checked_add(209)?;
     if index < 0 || index as usize >= TIMESTAMPS.len() {
         None
     } else {
@@ -10,11 +13,27 @@ fn try_year_starts0(republican_year0: i64) -> Option<i64> {
     }
 }
 
+/// # Panics
+///
+/// Panics if the start of the year cannot be represented by an `i64` timestamp, see [`checked_get_year_start`].
 pub fn get_year_start(republican_year: i64) -> i64 {
     get_year_start0(republican_year0(republican_year))
 }
 
-fn republican_year0(republican_year: i64) -> i64 {
+/// Returns `None` if the start of the year cannot be represented by an `i64` timestamp.
+pub fn checked_get_year_start(republican_year: i64) -> Option<i64> {
+    checked_get_year_start0(republican_year0(republican_year))
+}
+
+/// Same as [`checked_get_year_start`] with an explicit [`YearStartProvider`].
+pub fn checked_get_year_start_with<P: YearStartProvider + ?Sized>(
+    republican_year: i64,
+    provider: &P,
+) -> Option<i64> {
+    i64::try_from(provider.year_start0(republican_year0(republican_year))).ok()
+}
+
+pub(crate) fn republican_year0(republican_year: i64) -> i64 {
     if republican_year > 0 {
         republican_year - 1
     } else {
@@ -22,60 +41,139 @@ fn republican_year0(republican_year: i64) -> i64 {
     }
 }
 
+/// Returns the gregorian year in which a republican year (starting from 0) starts, as years start in September.
+pub(crate) fn gregorian_year_of_year0(republican_year0: i64) -> i128 {
+    republican_year0 as i128 + 1792
+}
+
+/// Returns the year (starting from 1) of a year starting from 0.
+pub(crate) fn republican_year(republican_year0: i64) -> i64 {
+    if republican_year0 >= 0 {
+        republican_year0 + 1
+    } else {
+        republican_year0
+    }
+}
+
+/// # Panics
+///
+/// Panics if the start of the year cannot be represented by an `i64` timestamp, see [`checked_get_year_start0`].
 pub fn get_year_start0(republican_year0: i64) -> i64 {
-    try_year_starts0(republican_year0).unwrap_or_else(|| {
-        if republican_year0 >= 0 {
-            let sextile_years_since_1208 = (republican_year0 + 1 - 1208) / 4;
-            let standard_years_since_1208 = republican_year0 + 1 - 1208 - sextile_years_since_1208;
-            let days_since_1208 = sextile_years_since_1208 * 366 + standard_years_since_1208 * 365;
-            get_year_start(1208) + days_since_1208 * REPUBLICAN_SECONDS_PER_DAY
-        } else {
-            let sextile_years_since_m210 = -(republican_year0 + 210) / 4;
-            let standard_years_since_m210 = -(republican_year0 + 210) - sextile_years_since_m210;
-            let days_since_m210 = sextile_years_since_m210 * 366 + standard_years_since_m210 * 365;
-            get_year_start(-209) - (days_since_m210 + 366) * REPUBLICAN_SECONDS_PER_DAY
-        }
-    })
+    checked_get_year_start0(republican_year0).expect("year start out of range")
+}
+
+/// Returns `None` if the start of the year cannot be represented by an `i64` timestamp.
+pub fn checked_get_year_start0(republican_year0: i64) -> Option<i64> {
+    i64::try_from(year_start0(republican_year0)).ok()
+}
+
+/// Computes the start of any year without overflowing, with the [`Imcce`] provider.
+pub(crate) fn year_start0(republican_year0: i64) -> i128 {
+    match try_year_starts0(republican_year0) {
+        Some(ts) => ts as i128,
+        None => extrapolate_year_start0(republican_year0),
+    }
+}
+
+/// Outside of the IMCCE table, years start on the day of the computed equinox.
+#[cfg(feature = "astronomical")]
+fn extrapolate_year_start0(republican_year0: i64) -> i128 {
+    let equinox = compute_equinox0(republican_year0);
+    equinox - equinox.rem_euclid(REPUBLICAN_SECONDS_PER_DAY as i128)
+}
+
+/// Outside of the IMCCE table, a sextile year is added every 4 years.
+#[cfg(not(feature = "astronomical"))]
+fn extrapolate_year_start0(republican_year0: i64) -> i128 {
+    let republican_year0 = republican_year0 as i128;
+    let seconds_per_day = REPUBLICAN_SECONDS_PER_DAY as i128;
+    if republican_year0 >= 0 {
+        let sextile_years_since_1208 = (republican_year0 + 1 - 1208) / 4;
+        let standard_years_since_1208 = republican_year0 + 1 - 1208 - sextile_years_since_1208;
+        let days_since_1208 = sextile_years_since_1208 * 366 + standard_years_since_1208 * 365;
+        get_year_start(1208) as i128 + days_since_1208 * seconds_per_day
+    } else {
+        let sextile_years_since_m210 = -(republican_year0 + 210) / 4;
+        let standard_years_since_m210 = -(republican_year0 + 210) - sextile_years_since_m210;
+        let days_since_m210 = sextile_years_since_m210 * 366 + standard_years_since_m210 * 365;
+        get_year_start(-209) as i128 - (days_since_m210 + 366) * seconds_per_day
+    }
 }
 
 pub fn get_day_count(republican_year: i64) -> i64 {
-    get_day_count0(republican_year0(republican_year))
+    get_day_count_with(republican_year, &Imcce)
 }
 
-fn get_day_count0(republican_year0: i64) -> i64 {
-    let year_start = get_year_start0(republican_year0);
-    let next_year_start = get_year_start0(republican_year0 + 1);
-    (next_year_start - year_start) / REPUBLICAN_SECONDS_PER_DAY
+/// Same as [`get_day_count`] with an explicit [`YearStartProvider`].
+pub fn get_day_count_with<P: YearStartProvider + ?Sized>(republican_year: i64, provider: &P) -> i64 {
+    get_day_count0(republican_year0(republican_year), provider)
 }
 
+pub(crate) fn get_day_count0<P: YearStartProvider + ?Sized>(republican_year0: i64, provider: &P) -> i64 {
+    let year_start = provider.year_start0(republican_year0);
+    let next_year_start = provider.year_start0(republican_year0 + 1);
+    ((next_year_start - year_start) / REPUBLICAN_SECONDS_PER_DAY as i128) as i64
+}
+
+#[cfg(not(feature = "astronomical"))]
 const AVERAGE_SECONDS_PER_YEAR: i64 =
     (4 * 365 * REPUBLICAN_SECONDS_PER_DAY + REPUBLICAN_SECONDS_PER_DAY) / 4;
+#[cfg(feature = "astronomical")]
+const AVERAGE_SECONDS_PER_YEAR: i64 = 36524219;
 
+/// Returns the year (starting from 0) containing the timestamp.
+///
+/// This is exact for any `i64` timestamp.
 pub fn ts_to_year0(ts: i64) -> i64 {
-    let estimated = ts / AVERAGE_SECONDS_PER_YEAR;
-    if estimated.abs() > 100_000 {
-        return estimated;
-    }
-
-    let mut year0 = estimated;
-    loop {
-        let year_start = get_year_start0(year0);
-        if year_start > ts {
-            // If the predicted year starts after the timestamp
-            year0 -= 1;
-            continue;
-        }
-        if year_start + get_day_count0(year0) * REPUBLICAN_SECONDS_PER_DAY <= ts {
-            // If the predicted year ends before the timestamp
-            year0 += 1;
-            continue;
+    ts_to_year0_with(ts, &Imcce)
+}
+
+/// Same as [`ts_to_year0`] with an explicit [`YearStartProvider`].
+pub fn ts_to_year0_with<P: YearStartProvider + ?Sized>(ts: i64, provider: &P) -> i64 {
+    let ts = ts as i128;
+    let estimated = (ts / AVERAGE_SECONDS_PER_YEAR as i128) as i64;
+
+    // Find a range of years containing the timestamp, with steps growing exponentially
+    let (mut low, mut high) = (estimated, estimated);
+    let mut step = 1i64;
+    while provider.year_start0(low) > ts {
+        high = low;
+        low = low.saturating_sub(step);
+        step = step.saturating_mul(2);
+    }
+    step = 1;
+    while provider.year_start0(high.saturating_add(1)) <= ts {
+        low = high;
+        high = high.saturating_add(step);
+        step = step.saturating_mul(2);
+    }
+
+    // Then find the last year starting before the timestamp
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+        if provider.year_start0(middle) <= ts {
+            low = middle;
+        } else {
+            high = middle - 1;
         }
-        return year0;
     }
+    low
+}
+
+/// Returns the franciade (starting from 0) of a year (starting from 0).
+///
+/// The first franciade contains the years -1, 1, 2 and 3.
+pub(crate) fn franciade0_of_year0(republican_year0: i64) -> i64 {
+    (republican_year0 as i128 + 1).div_euclid(4) as i64
 }
 
 pub fn ts_to_year(ts: i64) -> i64 {
-    let year0 = ts_to_year0(ts);
+    ts_to_year_with(ts, &Imcce)
+}
+
+/// Same as [`ts_to_year`] with an explicit [`YearStartProvider`].
+pub fn ts_to_year_with<P: YearStartProvider + ?Sized>(ts: i64, provider: &P) -> i64 {
+    let year0 = ts_to_year0_with(ts, provider);
     if year0 >= 0 {
         year0 + 1
     } else {
@@ -98,15 +196,18 @@ fn test_year_start() {
     assert_eq!(365, get_day_count(10));
     assert_eq!(366, get_day_count(11)); // sextile
 
-    assert_eq!(365, get_day_count(1208));
-    assert_eq!(365, get_day_count(1209));
-    assert_eq!(365, get_day_count(1210));
-    assert_eq!(366, get_day_count(1211)); // sextile
+    #[cfg(not(feature = "astronomical"))]
+    {
+        assert_eq!(365, get_day_count(1208));
+        assert_eq!(365, get_day_count(1209));
+        assert_eq!(365, get_day_count(1210));
+        assert_eq!(366, get_day_count(1211)); // sextile
 
-    assert_eq!(365, get_day_count(-211));
-    assert_eq!(365, get_day_count(-212));
-    assert_eq!(365, get_day_count(-213));
-    assert_eq!(366, get_day_count(-214)); // sextile
+        assert_eq!(365, get_day_count(-211));
+        assert_eq!(365, get_day_count(-212));
+        assert_eq!(365, get_day_count(-213));
+        assert_eq!(366, get_day_count(-214)); // sextile
+    }
 
     // Verify coherence
     let mut previous_year_start = get_year_start(1);
@@ -140,3 +241,67 @@ fn test_year_start() {
         assert_eq!(year0, ts_to_year0(get_year_start0(year0)));
     }
 }
+
+#[test]
+fn test_extreme_years() {
+    for ts in [i64::MIN, i64::MIN + 1, -1, 0, i64::MAX - 1, i64::MAX] {
+        let year0 = ts_to_year0(ts);
+        assert!(checked_get_year_start0(year0).is_none_or(|start| start <= ts));
+        assert!(checked_get_year_start0(year0 + 1).is_none_or(|next| next > ts));
+    }
+    for year0 in [
+        1_000_000,
+        -1_000_000,
+        100_000_000,
+        -100_000_000,
+        ts_to_year0(i64::MAX),
+        ts_to_year0(i64::MIN) + 1,
+    ] {
+        assert_eq!(year0, ts_to_year0(get_year_start0(year0)));
+    }
+    assert!(checked_get_year_start(i64::MAX).is_none());
+    assert!(checked_get_year_start(i64::MIN).is_none());
+    assert!(checked_get_year_start0(ts_to_year0(i64::MAX) + 1).is_none());
+    assert!(checked_get_year_start0(ts_to_year0(i64::MIN)).is_none());
+    assert!((365..=366).contains(&get_day_count(i64::MAX)));
+    assert!((365..=366).contains(&get_day_count(i64::MIN)));
+}
+
+#[cfg(feature = "astronomical")]
+#[test]
+fn test_astronomical_year_start() {
+    for year0 in -100_000..100_000 {
+        let day_count = get_day_count0(year0, &Imcce);
+        assert!((365..=366).contains(&day_count), "year0 {year0}: {day_count}");
+    }
+    for year0 in [-10_000, -3000, -210, 1208, 1500, 10_000] {
+        let equinox = compute_equinox0(year0);
+        let year_start = year_start0(year0);
+        assert!((year_start..year_start + REPUBLICAN_SECONDS_PER_DAY as i128).contains(&equinox));
+    }
+}
+
+#[test]
+fn test_provider() {
+    struct Shifted;
+
+    impl YearStartProvider for Shifted {
+        fn year_start0(&self, republican_year0: i64) -> i128 {
+            Imcce.year_start0(republican_year0) + REPUBLICAN_SECONDS_PER_DAY as i128
+        }
+    }
+
+    assert_eq!(checked_get_year_start_with(1, &Shifted), Some(REPUBLICAN_SECONDS_PER_DAY));
+    assert_eq!(ts_to_year0_with(0, &Shifted), -1);
+    assert_eq!(ts_to_year0_with(REPUBLICAN_SECONDS_PER_DAY, &Shifted), 0);
+    for year in [-3000, -1, 1, 3, 3000] {
+        assert_eq!(get_day_count_with(year, &Shifted), get_day_count(year));
+    }
+
+    let provider: &dyn YearStartProvider = &crate::Astronomical;
+    for ts in [i64::MIN, -1, 0, i64::MAX] {
+        let year0 = ts_to_year0_with(ts, provider);
+        assert!(provider.year_start0(year0) <= ts as i128);
+        assert!(provider.year_start0(year0 + 1) > ts as i128);
+    }
+}